@@ -0,0 +1,139 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Nominal solar and planetary conversion constants as defined by IAU 2015
+//! Resolution B3.
+
+use quantities::prelude::*;
+
+#[cfg(feature = "temperature")]
+use quantities::temperature::{Temperature, KELVIN};
+
+use crate::{
+    GravitationalParameter, Length, Luminosity,
+    CUBIC_METER_PER_SECOND_SQUARED, KILOMETER, SOLAR_LUMINOSITY,
+};
+
+/// Nominal solar radius: R☉ = 695700 km
+pub const NOMINAL_SOLAR_RADIUS: Length = Length::new(Amnt!(695700), KILOMETER);
+
+/// Nominal equatorial radius of the earth: R🜨e = 6378.1 km
+pub const NOMINAL_EARTH_EQUATORIAL_RADIUS: Length =
+    Length::new(Amnt!(6378.1), KILOMETER);
+
+/// Nominal polar radius of the earth: R🜨p = 6356.8 km
+pub const NOMINAL_EARTH_POLAR_RADIUS: Length =
+    Length::new(Amnt!(6356.8), KILOMETER);
+
+/// Nominal equatorial radius of jupiter: R♃e = 71492 km
+pub const NOMINAL_JUPITER_EQUATORIAL_RADIUS: Length =
+    Length::new(Amnt!(71492), KILOMETER);
+
+/// Nominal polar radius of jupiter: R♃p = 66854 km
+pub const NOMINAL_JUPITER_POLAR_RADIUS: Length =
+    Length::new(Amnt!(66854), KILOMETER);
+
+/// Nominal solar total luminosity: L☉ = 3.828·10²⁶ W
+pub const NOMINAL_SOLAR_LUMINOSITY: Luminosity =
+    Luminosity::new(Amnt!(1), SOLAR_LUMINOSITY);
+
+/// Nominal solar effective temperature: T☉eff = 5772 K
+#[cfg(feature = "temperature")]
+pub const NOMINAL_SOLAR_EFFECTIVE_TEMPERATURE: Temperature =
+    Temperature::new(Amnt!(5772), KELVIN);
+
+/// Nominal solar mass parameter: (GM)☉ = 1.3271244·10²⁰ m³/s²
+pub const NOMINAL_SOLAR_MASS_PARAMETER: GravitationalParameter =
+    GravitationalParameter::new(
+        Amnt!(1.3271244e20),
+        CUBIC_METER_PER_SECOND_SQUARED,
+    );
+
+/// Nominal terrestrial mass parameter: (GM)🜨 = 3.986004·10¹⁴ m³/s²
+pub const NOMINAL_EARTH_MASS_PARAMETER: GravitationalParameter =
+    GravitationalParameter::new(
+        Amnt!(3.986004e14),
+        CUBIC_METER_PER_SECOND_SQUARED,
+    );
+
+/// Nominal jovian mass parameter: (GM)♃ = 1.2668653·10¹⁷ m³/s²
+pub const NOMINAL_JUPITER_MASS_PARAMETER: GravitationalParameter =
+    GravitationalParameter::new(
+        Amnt!(1.2668653e17),
+        CUBIC_METER_PER_SECOND_SQUARED,
+    );
+
+#[cfg(test)]
+mod tests {
+    use quantities::assert_almost_eq;
+
+    use super::*;
+    use crate::{
+        ASTRONOMICAL_UNIT, CUBIC_ASTRONOMICAL_UNIT_PER_DAY_SQUARED,
+        EARTH_MASS, METER_PER_SECOND_SQUARED, SOLAR_MASS, WATT,
+    };
+
+    #[test]
+    fn test_solar_radius() {
+        let r = NOMINAL_SOLAR_RADIUS.convert(ASTRONOMICAL_UNIT);
+        assert_almost_eq!(r.amount(), Amnt!(0.004650467260962158));
+        assert!(NOMINAL_EARTH_EQUATORIAL_RADIUS > NOMINAL_EARTH_POLAR_RADIUS);
+        assert!(
+            NOMINAL_JUPITER_EQUATORIAL_RADIUS > NOMINAL_JUPITER_POLAR_RADIUS
+        );
+    }
+
+    #[test]
+    fn test_solar_luminosity() {
+        let l = NOMINAL_SOLAR_LUMINOSITY.convert(WATT);
+        assert_almost_eq!(l.amount(), Amnt!(3.828e26));
+    }
+
+    #[cfg(feature = "temperature")]
+    #[test]
+    fn test_solar_effective_temperature() {
+        use quantities::{
+            temperature::{DEGREE_CELSIUS, TEMPERATURE_CONVERTER},
+            Converter,
+        };
+        let t = TEMPERATURE_CONVERTER
+            .convert(&NOMINAL_SOLAR_EFFECTIVE_TEMPERATURE, DEGREE_CELSIUS)
+            .unwrap();
+        assert_almost_eq!(t.amount(), Amnt!(5498.85));
+    }
+
+    #[test]
+    fn test_mass_parameters() {
+        // The nominal mass parameters agree with the Gaussian gravitational
+        // constant k = 0.01720209895 and with the nominal mass ratio of the
+        // earth to the sun to a relative precision of 10⁻⁶.
+        let tolerance = Amnt!(1e-6);
+        let k = Amnt!(0.01720209895);
+        let mu = NOMINAL_SOLAR_MASS_PARAMETER
+            .equiv_amount(CUBIC_ASTRONOMICAL_UNIT_PER_DAY_SQUARED);
+        assert!((mu / (k * k) - Amnt!(1)).abs() < tolerance);
+        let ratio =
+            NOMINAL_EARTH_MASS_PARAMETER / NOMINAL_SOLAR_MASS_PARAMETER;
+        let expected = EARTH_MASS.ratio(&SOLAR_MASS);
+        assert!((ratio / expected - Amnt!(1)).abs() < tolerance);
+        // surface gravity without centrifugal effect
+        let r = NOMINAL_EARTH_EQUATORIAL_RADIUS;
+        let g = NOMINAL_EARTH_MASS_PARAMETER / (r * r);
+        assert_almost_eq!(
+            g.equiv_amount(METER_PER_SECOND_SQUARED),
+            Amnt!(9.798398133669465)
+        );
+        let r = NOMINAL_JUPITER_EQUATORIAL_RADIUS;
+        let g = NOMINAL_JUPITER_MASS_PARAMETER / (r * r);
+        assert_almost_eq!(
+            g.equiv_amount(METER_PER_SECOND_SQUARED),
+            Amnt!(24.786519476149145)
+        );
+    }
+}
@@ -11,7 +11,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(dead_code)]
 
-use quantities::prelude::*;
+use quantities::{prelude::*, ReciprocalEquivalence};
+
+pub mod constants;
+#[cfg(feature = "std")]
+pub mod magnitude;
+#[cfg(feature = "std")]
+pub mod orbital;
+mod si;
 
 #[quantity]
 #[ref_unit(Solar_Mass, "M☉", "Reference unit of quantity `Mass`")]
@@ -122,8 +129,296 @@ pub struct Duration {}
 /// | c      | Speed of Light       | ls/s              | 173.14463267424034   |
 pub struct Speed {}
 
+#[quantity(Speed / Duration)]
+#[ref_unit(
+    Astronomical_Units_per_Day_Squared,
+    "au/d²",
+    "Reference unit of quantity `Acceleration`"
+)]
+#[unit(Meter_per_Second_Squared, "m/s²", 0.049900175484249056, "SI unit")]
+#[unit(Standard_Gravity, "g₀", 0.489353555912611, "9.80665·m/s²")]
+/// Rate of change of an objects speed with respect to time
+///
+/// Definition: Speed/Duration
+///
+/// Reference unit: Astronomical Units per Day squared ('au/d²')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                     | Definition   | Equivalent in 'au/d²' |
+/// |--------|--------------------------|--------------|-----------------------|
+/// | m/s²   | Meter per Second squared | SI unit      | 0.049900175484249056  |
+/// | g₀     | Standard Gravity         | 9.80665·m/s² | 0.489353555912611     |
+pub struct Acceleration {}
+
+#[quantity(Length * Length)]
+#[ref_unit(
+    Square_Astronomical_Unit,
+    "au²",
+    "Reference unit of quantity `Area`"
+)]
+#[unit(Square_Kilometer, "km²", 4.468370499519714e-17, "km²")]
+#[unit(Square_Lightyear, "ly²", 3999433830.778111, "ly²")]
+#[unit(Square_Parsec, "pc²", 42545170296.15221, "pc²")]
+/// Extent of a two-dimensional surface
+///
+/// Definition: Length²
+///
+/// Reference unit: Square Astronomical Unit ('au²')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                | Definition     | Equivalent in 'au²'   |
+/// |--------|---------------------|----------------|-----------------------|
+/// | km²    | Square Kilometer    | km²            | 4.468370499519714e-17 |
+/// | ly²    | Square Lightyear    | ly²            | 3999433830.778111     |
+/// | pc²    | Square Parsec       | pc²            | 42545170296.15221     |
+pub struct Area {}
+
+#[quantity(Area * Length)]
+#[ref_unit(
+    Cubic_Astronomical_Unit,
+    "au³",
+    "Reference unit of quantity `Volume`"
+)]
+#[unit(Cubic_Kilometer, "km³", 2.98692118986137e-25, "km³")]
+#[unit(Cubic_Lightyear, "ly³", 252928503185660.94, "ly³")]
+#[unit(Cubic_Parsec, "pc³", 8775571307885554., "pc³")]
+/// Extent of a three-dimensional space
+///
+/// Definition: Length³
+///
+/// Reference unit: Cubic Astronomical Unit ('au³')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                | Definition     | Equivalent in 'au³'   |
+/// |--------|---------------------|----------------|-----------------------|
+/// | km³    | Cubic Kilometer     | km³            | 2.98692118986137e-25  |
+/// | ly³    | Cubic Lightyear     | ly³            | 252928503185660.94    |
+/// | pc³    | Cubic Parsec        | pc³            | 8775571307885554      |
+pub struct Volume {}
+
+#[quantity(Mass / Volume)]
+#[ref_unit(
+    Solar_Mass_per_Cubic_Astronomical_Unit,
+    "M☉/au³",
+    "Reference unit of quantity `Density`"
+)]
+#[unit(Solar_Mass_per_Cubic_Parsec, "M☉/pc³", 1.139526949204344e-16, "M☉/pc³")]
+#[unit(Kilogram_per_Cubic_Meter, "kg/m³", 1683.721764384234, "SI unit")]
+#[unit(Gram_per_Cubic_Centimeter, "g/cm³", 1683721.764384234, "g/cm³")]
+/// Mass per unit volume
+///
+/// Definition: Mass/Volume
+///
+/// Reference unit: Solar Mass per Cubic Astronomical Unit ('M☉/au³')
+///
+/// The equivalents of 'kg/m³' and 'g/cm³' are based on the nominal solar
+/// mass parameter (GM)☉ = 1.3271244e20 m³/s² (IAU 2015 Resolution B3) and
+/// G = 6.67430e-11 m³/(kg·s²).
+///
+/// Predefined units:
+///
+/// | Symbol | Name                        | Equivalent in 'M☉/au³' |
+/// |--------|-----------------------------|------------------------|
+/// | M☉/pc³ | Solar Mass per Cubic Parsec | 1.139526949204344e-16  |
+/// | kg/m³  | Kilogram per Cubic Meter    | 1683.721764384234      |
+/// | g/cm³  | Gram per Cubic Centimeter   | 1683721.764384234      |
+pub struct Density {}
+
+#[quantity(Acceleration * Area)]
+#[ref_unit(
+    Cubic_Astronomical_Unit_per_Day_Squared,
+    "au³/d²",
+    "Reference unit of quantity `GravitationalParameter`"
+)]
+#[unit(
+    Cubic_Meter_per_Second_Squared,
+    "m³/s²",
+    2.2297247205467532e-24,
+    "SI unit"
+)]
+#[unit(
+    Cubic_Kilometer_per_Second_Squared,
+    "km³/s²",
+    2.2297247205467534e-15,
+    "km³/s²"
+)]
+/// Product of the gravitational constant and the mass of a body
+///
+/// Definition: Acceleration·Area
+///
+/// Reference unit: Cubic Astronomical Unit per Day squared ('au³/d²')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                               | Equivalent in 'au³/d²' |
+/// |--------|------------------------------------|------------------------|
+/// | m³/s²  | Cubic Meter per Second squared     | 2.2297247205467532e-24 |
+/// | km³/s² | Cubic Kilometer per Second squared | 2.2297247205467534e-15 |
+pub struct GravitationalParameter {}
+
+#[quantity]
+#[ref_unit(
+    Solar_Luminosity,
+    "L☉",
+    "Reference unit of quantity `Luminosity` (= 3.828e26·W)"
+)]
+#[unit(Erg_per_Second, "erg/s", 2.612330198537095e-34, "10⁻⁷·W")]
+#[unit(Watt, "W", 2.612330198537095e-27, "SI unit")]
+/// Total power radiated by an astronomical object
+///
+/// The nominal solar luminosity is defined by IAU 2015 Resolution B3.
+///
+/// Reference unit: Solar Luminosity ('L☉')
+///
+/// Predefined units:
+///
+/// | Symbol | Name               | Definition        | Equivalent in 'L☉'    |
+/// |--------|--------------------|-------------------|-----------------------|
+/// | erg/s  | Erg per Second     | 10⁻⁷·W            | 2.612330198537095e-34 |
+/// | W      | Watt               | SI unit           | 2.612330198537095e-27 |
+pub struct Luminosity {}
+
+#[quantity(Luminosity / Area)]
+#[ref_unit(
+    Solar_Luminosity_per_Square_Astronomical_Unit,
+    "L☉/au²",
+    "Reference unit of quantity `Flux`"
+)]
+#[unit(
+    Erg_per_Second_per_Square_Centimeter,
+    "erg/(s·cm²)",
+    5.8462703547476285e-8,
+    "10⁻³·W/m²"
+)]
+#[unit(Watt_per_Square_Meter, "W/m²", 5.846270354747628e-5, "SI unit")]
+/// Power received per unit area, integrated over all frequencies
+///
+/// Definition: Luminosity/Area
+///
+/// Reference unit: Solar Luminosity per Square Astronomical Unit ('L☉/au²')
+///
+/// Predefined units:
+///
+/// | Symbol      | Name                                 | Equiv. in 'L☉/au²'  |
+/// |-------------|--------------------------------------|---------------------|
+/// | erg/(s·cm²) | Erg per Second per Square Centimeter | 5.84627035474763e-8 |
+/// | W/m²        | Watt per Square Meter                | 5.84627035474763e-5 |
+pub struct Flux {}
+
+#[quantity]
+#[ref_unit(Jansky, "Jy", "Reference unit of quantity `FluxDensity`")]
+#[unit(Microjansky, "µJy", 0.000001, "10⁻⁶·Jy")]
+#[unit(Millijansky, "mJy", 0.001, "0.001·Jy")]
+#[unit(Watt_per_Square_Meter_per_Hertz, "W/(m²·Hz)", 1e26, "10²⁶·Jy")]
+/// Spectral flux density, i.e. power received per unit area and per unit
+/// frequency
+///
+/// Reference unit: Jansky ('Jy' = 10⁻²⁶·W/(m²·Hz))
+///
+/// Predefined units:
+///
+/// | Symbol    | Name                            | Equivalent in 'Jy' |
+/// |-----------|---------------------------------|--------------------|
+/// | µJy       | Microjansky                     | 0.000001           |
+/// | mJy       | Millijansky                     | 0.001              |
+/// | W/(m²·Hz) | Watt per Square Meter per Hertz | 1e26               |
+pub struct FluxDensity {}
+
+#[quantity]
+#[ref_unit(Arcsecond, "″", "Reference unit of quantity `Angle`")]
+#[unit(Microarcsecond, "µas", 0.000001, "10⁻⁶·″")]
+#[unit(Milliarcsecond, "mas", 0.001, "0.001·″")]
+#[unit(Arcminute, "′", 60, "60·″")]
+#[unit(Degree, "°", 3600, "60·′")]
+#[unit(Radian, "rad", 206264.80624709636, "648000/π·″")]
+/// Angular distance or size on the celestial sphere
+///
+/// Reference unit: Arcsecond ('″')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in '″'   |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | µas    | Microarcsecond        | 10⁻⁶·″            | 0.000001            |
+/// | mas    | Milliarcsecond        | 0.001·″           | 0.001               |
+/// | ′      | Arcminute             | 60·″              | 60                  |
+/// | °      | Degree                | 60·′              | 3600                |
+/// | rad    | Radian                | 648000/π·″        | 206264.80624709636  |
+pub struct Angle {}
+
+/// Equivalence of the annual parallax of an object and its distance
+/// (d = 1 pc / p, where p is given in arcseconds).
+pub const PARALLAX_DISTANCE: ReciprocalEquivalence<Angle, Length> =
+    ReciprocalEquivalence::new(Amnt!(206264.80624709636));
+
+// 1/(4π)
+const INV_FOUR_PI: AmountT = Amnt!(0.07957747154594767);
+
+impl Flux {
+    /// Returns the flux received at `distance` from an object radiating
+    /// isotropically with the given `luminosity`: F = L/(4π·d²).
+    #[must_use]
+    pub fn from_luminosity(luminosity: Luminosity, distance: Length) -> Self {
+        luminosity / (distance * distance) * INV_FOUR_PI
+    }
+}
+
+impl Luminosity {
+    /// Returns the luminosity of an object radiating isotropically, whose
+    /// `flux` is received at `distance`: L = 4π·d²·F.
+    #[must_use]
+    pub fn from_flux(flux: Flux, distance: Length) -> Self {
+        flux * (distance * distance) / INV_FOUR_PI
+    }
+}
+
+#[cfg(feature = "frequency")]
+impl FluxDensity {
+    /// Returns the flux received within the given `bandwidth`, assuming a
+    /// constant flux density over the band: F = Sν·Δν.
+    #[must_use]
+    pub fn flux_in_band(
+        &self,
+        bandwidth: quantities::frequency::Frequency,
+    ) -> Flux {
+        let hz = bandwidth.equiv_amount(quantities::frequency::HERTZ);
+        Flux::new(
+            self.equiv_amount(WATT_PER_SQUARE_METER_PER_HERTZ) * hz,
+            WATT_PER_SQUARE_METER,
+        )
+    }
+}
+
+impl Angle {
+    /// Returns the angular size of an object with the linear `size` seen
+    /// at `distance`.
+    ///
+    /// The small-angle approximation θ = size/distance is used, so `size`
+    /// should be small compared to `distance`.
+    #[must_use]
+    pub fn from_size(size: Length, distance: Length) -> Self {
+        Self::new(size / distance, RADIAN)
+    }
+}
+
+impl Length {
+    /// Returns the linear size of an object with the given `angular_size`
+    /// seen at `distance`.
+    ///
+    /// The small-angle approximation size = θ·distance is used.
+    #[must_use]
+    pub fn from_angular_size(angular_size: Angle, distance: Length) -> Self {
+        distance * angular_size.equiv_amount(RADIAN)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use quantities::{assert_almost_eq, Equivalence};
+
     use super::*;
 
     #[test]
@@ -176,4 +471,159 @@ mod tests {
         #[cfg(feature = "std")]
         assert_eq!(v.to_string(), "235.4 c");
     }
+
+    #[test]
+    fn test_acceleration() {
+        let v = Amnt!(9.80665) * METER_PER_SECOND;
+        let t = Amnt!(1) * SECOND;
+        let a = v / t;
+        assert_almost_eq!(
+            a.equiv_amount(STANDARD_GRAVITY),
+            Amnt!(1.0000000000000002)
+        );
+        let v = a * (Amnt!(2) * SECOND);
+        assert_almost_eq!(v.equiv_amount(METER_PER_SECOND), Amnt!(19.6133));
+    }
+
+    #[test]
+    fn test_area_and_volume() {
+        let l = Amnt!(2) * PARSEC;
+        let a = l * l;
+        assert_almost_eq!(a.equiv_amount(SQUARE_PARSEC), Amnt!(4));
+        let v = a * l;
+        assert_almost_eq!(v.equiv_amount(CUBIC_PARSEC), Amnt!(8));
+        assert_almost_eq!((v / l / l).equiv_amount(PARSEC), Amnt!(2));
+    }
+
+    #[test]
+    fn test_density() {
+        // mean density of the sun
+        let r = constants::NOMINAL_SOLAR_RADIUS;
+        let v = r * r * r * Amnt!(4.1887902047863905);
+        let d = Amnt!(1) * SOLAR_MASS / v;
+        assert_almost_eq!(
+            d.equiv_amount(GRAM_PER_CUBIC_CENTIMETER),
+            Amnt!(1.4097798243075257)
+        );
+        let m = d * v;
+        assert_almost_eq!(m.equiv_amount(SOLAR_MASS), Amnt!(1));
+        let d = Amnt!(0.1) * SOLAR_MASS_PER_CUBIC_PARSEC;
+        assert_almost_eq!(
+            d.equiv_amount(KILOGRAM_PER_CUBIC_METER),
+            Amnt!(6.767905323247328e-21)
+        );
+    }
+
+    #[test]
+    fn test_luminosity() {
+        assert!(SOLAR_LUMINOSITY.is_ref_unit());
+        let l = Amnt!(3.828e26) * WATT;
+        assert_almost_eq!(l.equiv_amount(SOLAR_LUMINOSITY), Amnt!(1));
+        let l = Amnt!(25.4) * SOLAR_LUMINOSITY;
+        assert_almost_eq!(l.equiv_amount(ERG_PER_SECOND), Amnt!(9.72312e34));
+        #[cfg(feature = "std")]
+        assert_eq!(l.to_string(), "25.4 L☉");
+    }
+
+    #[test]
+    fn test_flux_density() {
+        let f = Amnt!(250) * MILLIJANSKY;
+        assert_almost_eq!(f.equiv_amount(JANSKY), Amnt!(0.25));
+        assert_almost_eq!(
+            f.equiv_amount(WATT_PER_SQUARE_METER_PER_HERTZ),
+            Amnt!(2.5e-27)
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(f.to_string(), "250 mJy");
+    }
+
+    #[test]
+    fn test_flux() {
+        let f = Amnt!(1) * WATT_PER_SQUARE_METER;
+        assert_almost_eq!(
+            f.equiv_amount(ERG_PER_SECOND_PER_SQUARE_CENTIMETER),
+            Amnt!(1000)
+        );
+        let l = Amnt!(2) * SOLAR_LUMINOSITY;
+        let d = Amnt!(4) * ASTRONOMICAL_UNIT;
+        let f: Flux = l / (d * d);
+        assert_almost_eq!(f.amount(), Amnt!(0.125));
+        assert_eq!(f.unit(), SOLAR_LUMINOSITY_PER_SQUARE_ASTRONOMICAL_UNIT);
+        #[cfg(feature = "std")]
+        assert_eq!(f.to_string(), "0.125 L☉/au²");
+    }
+
+    #[test]
+    fn test_flux_from_luminosity() {
+        // The nominal total solar irradiance is 1361 W/m² (IAU 2015
+        // Resolution B3); it is derived from measurements, not from L☉, so
+        // only agreement to 0.1 % can be expected.
+        let f = Flux::from_luminosity(
+            constants::NOMINAL_SOLAR_LUMINOSITY,
+            Amnt!(1) * ASTRONOMICAL_UNIT,
+        );
+        let tsi = Amnt!(1361);
+        let f = f.equiv_amount(WATT_PER_SQUARE_METER);
+        assert!(((f - tsi) / tsi).abs() < Amnt!(1e-3));
+        // Vega, 7.68 pc, 40.12 L☉
+        let l = Amnt!(40.12) * SOLAR_LUMINOSITY;
+        let d = Amnt!(7.68) * PARSEC;
+        let f = Flux::from_luminosity(l, d);
+        assert_almost_eq!(Luminosity::from_flux(f, d).amount(), l.amount());
+    }
+
+    #[cfg(feature = "frequency")]
+    #[test]
+    fn test_flux_in_band() {
+        let s = Amnt!(2.5) * JANSKY;
+        let bw = Amnt!(4) * quantities::frequency::GIGAHERTZ;
+        let f = s.flux_in_band(bw);
+        assert_almost_eq!(f.equiv_amount(WATT_PER_SQUARE_METER), Amnt!(1e-16));
+    }
+
+    #[test]
+    fn test_angle() {
+        let a = Amnt!(1) * DEGREE;
+        assert_almost_eq!(a.equiv_amount(MILLIARCSECOND), Amnt!(3600000));
+        let a = Amnt!(0.5) * ARCMINUTE;
+        assert_almost_eq!(a.equiv_amount(ARCSECOND), Amnt!(30));
+        #[cfg(feature = "std")]
+        assert_eq!(a.to_string(), "0.5 ′");
+    }
+
+    #[test]
+    fn test_angular_size() {
+        // The apparent diameter of the sun at 1 au is 1919″ (NASA Sun Fact
+        // Sheet), given to 4 significant digits.
+        let a = Angle::from_size(
+            Amnt!(2) * constants::NOMINAL_SOLAR_RADIUS,
+            Amnt!(1) * ASTRONOMICAL_UNIT,
+        );
+        assert_eq!(a.unit(), RADIAN);
+        let a = a.equiv_amount(ARCSECOND);
+        assert!(((a - Amnt!(1919)) / Amnt!(1919)).abs() < Amnt!(1e-3));
+        // Moon: diameter 3474.8 km, mean distance 384400 km
+        let a = Angle::from_size(
+            Amnt!(3474.8) * KILOMETER,
+            Amnt!(384400) * KILOMETER,
+        );
+        assert_almost_eq!(
+            a.equiv_amount(RADIAN),
+            Amnt!(3474.8) / Amnt!(384400)
+        );
+        let d = Amnt!(384400) * KILOMETER;
+        let l = Length::from_angular_size(a, d);
+        assert_almost_eq!(l.equiv_amount(KILOMETER), Amnt!(3474.8));
+    }
+
+    #[test]
+    fn test_parallax_distance() {
+        // Proxima Centauri
+        let p = Amnt!(768.0665) * MILLIARCSECOND;
+        let d = PARALLAX_DISTANCE.convert(&p, PARSEC).unwrap();
+        assert_almost_eq!(d.amount(), Amnt!(1.3019705975979945));
+        let d = Amnt!(10) * PARSEC;
+        let p = PARALLAX_DISTANCE.convert_back(&d, MILLIARCSECOND).unwrap();
+        assert_almost_eq!(p.amount(), Amnt!(100));
+    }
 }
@@ -0,0 +1,142 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Functions handling apparent and absolute magnitudes.
+//!
+//! Magnitudes are logarithmic measures of brightness, given as plain
+//! amounts: a difference of 5 magnitudes corresponds to a factor of 100 in
+//! flux, with brighter objects having smaller magnitudes.
+
+use quantities::prelude::*;
+
+use crate::{
+    FluxDensity, Length, Luminosity, JANSKY, PARSEC, SOLAR_LUMINOSITY,
+};
+
+/// Zero point of the absolute bolometric magnitude scale: L₀ = 3.0128e28 W
+/// (IAU 2015 Resolution B2)
+pub const BOLOMETRIC_ZERO_POINT: Luminosity =
+    Luminosity::new(Amnt!(78.7042842215256), SOLAR_LUMINOSITY);
+
+/// Zero point of the AB magnitude system: F₀ = 3631 Jy
+pub const AB_ZERO_POINT: FluxDensity = FluxDensity::new(Amnt!(3631), JANSKY);
+
+#[inline]
+fn pogson(ratio: AmountT) -> AmountT {
+    Amnt!(-2.5) * ratio.log10()
+}
+
+#[inline]
+fn inverse_pogson(magnitude: AmountT) -> AmountT {
+    Amnt!(10).powf(Amnt!(-0.4) * magnitude)
+}
+
+/// Returns the ratio of the fluxes of two objects whose magnitudes differ
+/// by `delta`, i.e. F₁/F₂ for m₁ - m₂ = `delta`.
+#[must_use]
+pub fn flux_ratio(delta: AmountT) -> AmountT {
+    inverse_pogson(delta)
+}
+
+/// Returns the difference of the magnitudes m₁ - m₂ of two objects whose
+/// fluxes have the ratio F₁/F₂ = `ratio`.
+#[must_use]
+pub fn magnitude_difference(ratio: AmountT) -> AmountT {
+    pogson(ratio)
+}
+
+/// Returns the distance modulus m - M of an object at `distance`.
+#[must_use]
+pub fn distance_modulus(distance: Length) -> AmountT {
+    Amnt!(5) * (distance.equiv_amount(PARSEC) / Amnt!(10)).log10()
+}
+
+/// Returns the absolute magnitude of an object with apparent magnitude
+/// `apparent` at `distance`.
+#[must_use]
+pub fn absolute_magnitude(apparent: AmountT, distance: Length) -> AmountT {
+    apparent - distance_modulus(distance)
+}
+
+/// Returns the apparent magnitude of an object with absolute magnitude
+/// `absolute` at `distance`.
+#[must_use]
+pub fn apparent_magnitude(absolute: AmountT, distance: Length) -> AmountT {
+    absolute + distance_modulus(distance)
+}
+
+/// Returns the absolute bolometric magnitude of an object with the given
+/// `luminosity`.
+#[must_use]
+pub fn bolometric_magnitude(luminosity: Luminosity) -> AmountT {
+    pogson(luminosity / BOLOMETRIC_ZERO_POINT)
+}
+
+/// Returns the luminosity of an object with the given absolute bolometric
+/// `magnitude`.
+#[must_use]
+pub fn luminosity_from_bolometric_magnitude(magnitude: AmountT) -> Luminosity {
+    BOLOMETRIC_ZERO_POINT * inverse_pogson(magnitude)
+}
+
+/// Returns the AB magnitude corresponding to `flux_density`.
+#[must_use]
+pub fn ab_magnitude(flux_density: FluxDensity) -> AmountT {
+    pogson(flux_density / AB_ZERO_POINT)
+}
+
+/// Returns the flux density corresponding to the AB magnitude `magnitude`.
+#[must_use]
+pub fn flux_density_from_ab_magnitude(magnitude: AmountT) -> FluxDensity {
+    AB_ZERO_POINT * inverse_pogson(magnitude)
+}
+
+#[cfg(test)]
+mod tests {
+    use quantities::assert_almost_eq;
+
+    use super::*;
+    use crate::{ASTRONOMICAL_UNIT, MILLIJANSKY, WATT};
+
+    #[test]
+    fn test_flux_ratio() {
+        assert_almost_eq!(flux_ratio(Amnt!(5)), Amnt!(0.01));
+        assert_almost_eq!(flux_ratio(Amnt!(-5)), Amnt!(100));
+        assert_almost_eq!(magnitude_difference(Amnt!(0.01)), Amnt!(5));
+    }
+
+    #[test]
+    fn test_distance_modulus() {
+        let d = Amnt!(1) * crate::KILOPARSEC;
+        assert_almost_eq!(distance_modulus(d), Amnt!(10));
+        assert_almost_eq!(absolute_magnitude(Amnt!(12.5), d), Amnt!(2.5));
+        // the sun seen from earth
+        let d = Amnt!(1) * ASTRONOMICAL_UNIT;
+        assert_almost_eq!(
+            apparent_magnitude(Amnt!(4.74), d),
+            Amnt!(-26.832125665882295)
+        );
+    }
+
+    #[test]
+    fn test_bolometric_magnitude() {
+        let l = Amnt!(1) * SOLAR_LUMINOSITY;
+        assert_almost_eq!(bolometric_magnitude(l), Amnt!(4.7399959339194595));
+        let l = luminosity_from_bolometric_magnitude(Amnt!(0));
+        assert_almost_eq!(l.equiv_amount(WATT), Amnt!(3.0128e28));
+    }
+
+    #[test]
+    fn test_ab_magnitude() {
+        let f = Amnt!(1) * MILLIJANSKY;
+        assert_almost_eq!(ab_magnitude(f), Amnt!(16.40006562228223));
+        let f = flux_density_from_ab_magnitude(Amnt!(20));
+        assert_almost_eq!(f.equiv_amount(JANSKY), Amnt!(3.631e-5));
+    }
+}
@@ -0,0 +1,266 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Functions of celestial mechanics, based on Kepler's third law and the
+//! vis-viva equation.
+//!
+//! All calculations are done in astronomical units, using the Gaussian
+//! gravitational constant, so that G = k² au³/(M☉·d²).
+
+use quantities::prelude::*;
+
+use crate::{
+    Duration, Length, Mass, Speed, ASTRONOMICAL_UNIT,
+    ASTRONOMICAL_UNITS_PER_DAY, DAY, SOLAR_MASS,
+};
+
+/// Gaussian gravitational constant: k = 0.01720209895 au^(3/2)/(d·M☉^(1/2))
+pub const GAUSSIAN_GRAVITATIONAL_CONSTANT: AmountT = Amnt!(0.01720209895);
+
+// `AmountT` may be `f32` or `f64`, so `core::f64::consts::TAU` can't be used.
+#[allow(clippy::approx_constant)]
+const TAU: AmountT = Amnt!(6.283185307179586);
+
+// Returns the gravitational parameter G·M in au³/d².
+#[inline]
+fn gravitational_parameter(mass: Mass) -> AmountT {
+    GAUSSIAN_GRAVITATIONAL_CONSTANT
+        * GAUSSIAN_GRAVITATIONAL_CONSTANT
+        * mass.equiv_amount(SOLAR_MASS)
+}
+
+/// Returns the period of an orbit with the given `semi_major_axis` of two
+/// bodies with masses `primary` and `secondary`, or `None` if
+/// `semi_major_axis` or the total mass is not positive.
+#[must_use]
+pub fn orbital_period(
+    semi_major_axis: Length,
+    primary: Mass,
+    secondary: Mass,
+) -> Option<Duration> {
+    let a = semi_major_axis.equiv_amount(ASTRONOMICAL_UNIT);
+    let mu = gravitational_parameter(primary + secondary);
+    if a > Amnt!(0) && mu > Amnt!(0) {
+        let t = TAU * (a * a * a / mu).sqrt();
+        t.is_finite().then(|| Duration::new(t, DAY))
+    } else {
+        None
+    }
+}
+
+/// Returns the semi-major axis of an orbit with the given `period` of two
+/// bodies with masses `primary` and `secondary`, or `None` if `period` or
+/// the total mass is not positive.
+#[must_use]
+pub fn semi_major_axis(
+    period: Duration,
+    primary: Mass,
+    secondary: Mass,
+) -> Option<Length> {
+    let n = period.equiv_amount(DAY) / TAU;
+    let mu = gravitational_parameter(primary + secondary);
+    if n > Amnt!(0) && mu > Amnt!(0) {
+        let a = (mu * n * n).cbrt();
+        a.is_finite().then(|| Length::new(a, ASTRONOMICAL_UNIT))
+    } else {
+        None
+    }
+}
+
+/// Returns the speed needed to escape from the gravitational field of a
+/// body with the given `mass` at the given `distance` from its center, or
+/// `None` if `distance` is not positive.
+#[must_use]
+pub fn escape_speed(mass: Mass, distance: Length) -> Option<Speed> {
+    let r = distance.equiv_amount(ASTRONOMICAL_UNIT);
+    let mu = gravitational_parameter(mass);
+    speed_from_square(Amnt!(2) * mu / r, r)
+}
+
+/// Returns the speed of a body on a circular orbit with the given `radius`
+/// around a body with the given `mass`, or `None` if `radius` is not
+/// positive.
+#[must_use]
+pub fn circular_orbital_speed(mass: Mass, radius: Length) -> Option<Speed> {
+    let r = radius.equiv_amount(ASTRONOMICAL_UNIT);
+    let mu = gravitational_parameter(mass);
+    speed_from_square(mu / r, r)
+}
+
+/// Returns the speed of a body at the given `distance` from a body with the
+/// given `mass` on an orbit with the given `semi_major_axis`, or `None` if
+/// `distance` is not positive or the body can't reach `distance` on that
+/// orbit, i.e. if `distance` exceeds twice a positive `semi_major_axis`.
+///
+/// A negative `semi_major_axis` denotes a hyperbolic orbit.
+#[must_use]
+pub fn orbital_speed(
+    mass: Mass,
+    distance: Length,
+    semi_major_axis: Length,
+) -> Option<Speed> {
+    let r = distance.equiv_amount(ASTRONOMICAL_UNIT);
+    let a = semi_major_axis.equiv_amount(ASTRONOMICAL_UNIT);
+    let mu = gravitational_parameter(mass);
+    speed_from_square(mu * (Amnt!(2) / r - Amnt!(1) / a), r)
+}
+
+// Returns the speed with the given square (in au²/d²), or `None` if the
+// distance `r` (in au) it relates to is not positive or the square is
+// negative or NaN.
+#[inline]
+fn speed_from_square(v2: AmountT, r: AmountT) -> Option<Speed> {
+    if r > Amnt!(0) && v2 >= Amnt!(0) && v2.is_finite() {
+        Some(Speed::new(v2.sqrt(), ASTRONOMICAL_UNITS_PER_DAY))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quantities::assert_almost_eq;
+
+    use super::*;
+    use crate::{
+        constants::NOMINAL_EARTH_EQUATORIAL_RADIUS, EARTH_MASS, EARTH_PERIOD,
+        JULIAN_YEAR, JUPITER_MASS, METER_PER_SECOND,
+    };
+
+    // Relative tolerance for values that are exact by definition.
+    const EPS: AmountT = Amnt!(16) * AmountT::EPSILON;
+
+    // Returns the deviation of `x` from `y`, relative to `y`.
+    fn rel_dev(x: AmountT, y: AmountT) -> AmountT {
+        ((x - y) / y).abs()
+    }
+
+    #[test]
+    fn test_orbital_period() {
+        let one_au = Amnt!(1) * ASTRONOMICAL_UNIT;
+        let sun = Amnt!(1) * SOLAR_MASS;
+        // Gaussian year: 2π/k = 365.2568983263281 d by definition
+        let t = orbital_period(one_au, sun, Amnt!(0) * SOLAR_MASS).unwrap();
+        assert_eq!(t.unit(), DAY);
+        assert!(rel_dev(t.amount(), Amnt!(365.2568983263281)) < EPS);
+        // sidereal year: 365.256363004 d (IERS); agreement within 10⁻⁶
+        let t = orbital_period(one_au, sun, Amnt!(1) * EARTH_MASS).unwrap();
+        assert!(rel_dev(t.equiv_amount(EARTH_PERIOD), Amnt!(1)) < Amnt!(1e-6));
+        // sidereal period of jupiter: 4332.589 d, with a semi-major axis of
+        // 5.2038 au (NASA planetary fact sheet); agreement within 0.1 %
+        let t = orbital_period(
+            Amnt!(5.2038) * ASTRONOMICAL_UNIT,
+            sun,
+            Amnt!(1) * JUPITER_MASS,
+        )
+        .unwrap();
+        assert!(rel_dev(t.amount(), Amnt!(4332.589)) < Amnt!(1e-3));
+    }
+
+    #[test]
+    fn test_orbital_period_bounds() {
+        let sun = Amnt!(1) * SOLAR_MASS;
+        let no_mass = Amnt!(0) * SOLAR_MASS;
+        let one_au = Amnt!(1) * ASTRONOMICAL_UNIT;
+        assert!(orbital_period(Amnt!(0) * ASTRONOMICAL_UNIT, sun, no_mass)
+            .is_none());
+        assert!(orbital_period(Amnt!(-1) * ASTRONOMICAL_UNIT, sun, no_mass)
+            .is_none());
+        assert!(orbital_period(one_au, no_mass, no_mass).is_none());
+        assert!(orbital_period(one_au, -sun, no_mass).is_none());
+    }
+
+    #[test]
+    fn test_semi_major_axis() {
+        let sun = Amnt!(1) * SOLAR_MASS;
+        let a = semi_major_axis(
+            Amnt!(1) * JULIAN_YEAR,
+            sun,
+            Amnt!(0) * SOLAR_MASS,
+        )
+        .unwrap();
+        assert_eq!(a.unit(), ASTRONOMICAL_UNIT);
+        assert_almost_eq!(a.amount(), Amnt!(0.9999874091393869));
+        let earth = Amnt!(1) * EARTH_MASS;
+        let t = orbital_period(Amnt!(1) * ASTRONOMICAL_UNIT, sun, earth)
+            .unwrap();
+        let a = semi_major_axis(t, sun, earth).unwrap();
+        assert_almost_eq!(a.amount(), Amnt!(1));
+    }
+
+    #[test]
+    fn test_semi_major_axis_bounds() {
+        let sun = Amnt!(1) * SOLAR_MASS;
+        let no_mass = Amnt!(0) * SOLAR_MASS;
+        let one_year = Amnt!(1) * JULIAN_YEAR;
+        assert!(semi_major_axis(Amnt!(0) * DAY, sun, no_mass).is_none());
+        assert!(semi_major_axis(Amnt!(-1) * DAY, sun, no_mass).is_none());
+        assert!(semi_major_axis(one_year, no_mass, no_mass).is_none());
+        assert!(semi_major_axis(one_year, -sun, no_mass).is_none());
+    }
+
+    #[test]
+    fn test_escape_speed() {
+        let earth = Amnt!(1) * EARTH_MASS;
+        let v = escape_speed(earth, NOMINAL_EARTH_EQUATORIAL_RADIUS).unwrap();
+        assert_eq!(v.unit(), ASTRONOMICAL_UNITS_PER_DAY);
+        assert_almost_eq!(
+            v.equiv_amount(METER_PER_SECOND),
+            Amnt!(11179.90775609543)
+        );
+        assert!(escape_speed(earth, Amnt!(0) * ASTRONOMICAL_UNIT).is_none());
+        assert!(escape_speed(earth, Amnt!(-1) * ASTRONOMICAL_UNIT).is_none());
+    }
+
+    #[test]
+    fn test_orbital_speed() {
+        let sun = Amnt!(1) * SOLAR_MASS;
+        let one_au = Amnt!(1) * ASTRONOMICAL_UNIT;
+        let v = circular_orbital_speed(sun, one_au).unwrap();
+        assert_almost_eq!(v.amount(), GAUSSIAN_GRAVITATIONAL_CONSTANT);
+        assert_almost_eq!(
+            v.equiv_amount(METER_PER_SECOND),
+            Amnt!(29784.69183438317)
+        );
+        assert_almost_eq!(
+            orbital_speed(sun, one_au, one_au).unwrap().amount(),
+            v.amount()
+        );
+        // earth at perihelion
+        let v = orbital_speed(sun, Amnt!(0.98329) * ASTRONOMICAL_UNIT, one_au)
+            .unwrap();
+        assert_almost_eq!(
+            v.equiv_amount(METER_PER_SECOND),
+            Amnt!(30286.622707626947)
+        );
+    }
+
+    #[test]
+    fn test_orbital_speed_bounds() {
+        let sun = Amnt!(1) * SOLAR_MASS;
+        let zero = Amnt!(0) * ASTRONOMICAL_UNIT;
+        let one_au = Amnt!(1) * ASTRONOMICAL_UNIT;
+        let two_au = Amnt!(2) * ASTRONOMICAL_UNIT;
+        assert!(circular_orbital_speed(sun, zero).is_none());
+        assert!(orbital_speed(sun, zero, one_au).is_none());
+        // aphelion of a degenerate orbit: the body is at rest
+        let v = orbital_speed(sun, two_au, one_au).unwrap();
+        assert_eq!(v.amount(), Amnt!(0));
+        // beyond the aphelion
+        let r = Amnt!(2.5) * ASTRONOMICAL_UNIT;
+        assert!(orbital_speed(sun, r, one_au).is_none());
+        // hyperbolic orbit
+        let v = orbital_speed(sun, r, Amnt!(-1) * ASTRONOMICAL_UNIT).unwrap();
+        assert_almost_eq!(
+            v.amount(),
+            GAUSSIAN_GRAVITATIONAL_CONSTANT * Amnt!(1.8).sqrt()
+        );
+        assert!(orbital_speed(sun, one_au, zero).is_none());
+    }
+}
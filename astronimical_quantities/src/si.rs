@@ -0,0 +1,268 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Conversions between the astronomical quantities and the corresponding
+//! quantities of crate `quantities`.
+//!
+//! Each conversion is available if the feature with the name of the
+//! quantity in crate `quantities` is enabled. The converted value keeps the
+//! unit of the original value if the target quantity has a unit with the
+//! same symbol (like 'km' or 'h'), otherwise it is given in the reference
+//! unit of the target quantity.
+
+use quantities::prelude::*;
+
+// Returns the quantity equivalent to `amnt` given in the reference unit of
+// `Q`, expressed in the unit of `Q` with the given `symbol` or in the
+// reference unit, if there is no such unit.
+fn from_ref_amount<Q>(amnt: AmountT, symbol: &str) -> Q
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+{
+    Q::UnitType::iter()
+        .find(|unit| unit.symbol() == symbol)
+        .map_or_else(
+            || Q::new(amnt, Q::REF_UNIT),
+            |unit| Q::new(amnt / unit.scale(), unit),
+        )
+}
+
+// Implements `From` in both directions for the astronomical quantity `$qty`
+// and the quantity `$si_qty` of crate `quantities`, where one reference unit
+// of `$qty` equals `$factor` reference units of `$si_qty`.
+macro_rules! impl_si_conversion {
+    ($feature:literal, $qty:ty, $si_qty:ty, $factor:literal) => {
+        #[cfg(feature = $feature)]
+        impl From<$qty> for $si_qty {
+            fn from(qty: $qty) -> Self {
+                let amnt = qty.equiv_amount(<$qty>::REF_UNIT) * Amnt!($factor);
+                from_ref_amount(amnt, &qty.unit().symbol())
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl From<$si_qty> for $qty {
+            fn from(qty: $si_qty) -> Self {
+                let amnt =
+                    qty.equiv_amount(<$si_qty>::REF_UNIT) / Amnt!($factor);
+                from_ref_amount(amnt, &qty.unit().symbol())
+            }
+        }
+    };
+}
+
+impl_si_conversion!(
+    "length",
+    crate::Length,
+    quantities::length::Length,
+    149597870700.
+);
+impl_si_conversion!(
+    "mass",
+    crate::Mass,
+    quantities::mass::Mass,
+    1.988409870698051e30
+);
+impl_si_conversion!(
+    "duration",
+    crate::Duration,
+    quantities::duration::Duration,
+    86400.
+);
+impl_si_conversion!(
+    "speed",
+    crate::Speed,
+    quantities::speed::Speed,
+    1731456.8368055555
+);
+impl_si_conversion!(
+    "acceleration",
+    crate::Acceleration,
+    quantities::acceleration::Acceleration,
+    20.040009685249483
+);
+impl_si_conversion!(
+    "area",
+    crate::Area,
+    quantities::area::Area,
+    2.237952291797392e22
+);
+impl_si_conversion!(
+    "volume",
+    crate::Volume,
+    quantities::volume::Volume,
+    3.347928975810749e33
+);
+impl_si_conversion!(
+    "density",
+    crate::Density,
+    quantities::density::Density,
+    0.0005939223576917515
+);
+impl_si_conversion!(
+    "power",
+    crate::Luminosity,
+    quantities::power::Power,
+    3.828e26
+);
+impl_si_conversion!(
+    "angle",
+    crate::Angle,
+    quantities::angle::Angle,
+    4.84813681109536e-6
+);
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use quantities::{assert_almost_eq, prelude::*};
+
+    #[cfg(feature = "length")]
+    #[test]
+    fn test_length() {
+        use quantities::length::{Length, KILOMETER, METER};
+
+        let l = Amnt!(1) * crate::LIGHTYEAR;
+        let si = Length::from(l).convert(KILOMETER);
+        assert_almost_eq!(si.amount(), Amnt!(9460730472580.8));
+        let l = Amnt!(1) * crate::ASTRONOMICAL_UNIT;
+        let si = Length::from(l);
+        assert_eq!(si.unit(), METER);
+        assert_almost_eq!(si.amount(), Amnt!(149597870700.));
+        // units with the same symbol are kept
+        let si = Amnt!(695700) * KILOMETER;
+        let l = crate::Length::from(si);
+        assert_eq!(l.unit(), crate::KILOMETER);
+        assert_almost_eq!(l.amount(), Amnt!(695700));
+        let si: Length = l.into();
+        assert_eq!(si.unit(), KILOMETER);
+        assert_almost_eq!(si.amount(), Amnt!(695700));
+    }
+
+    #[cfg(feature = "mass")]
+    #[test]
+    fn test_mass() {
+        use quantities::mass::{Mass, KILOGRAM};
+
+        let m = Mass::from(Amnt!(1) * crate::EARTH_MASS);
+        assert_eq!(m.unit(), KILOGRAM);
+        assert_almost_eq!(m.amount(), Amnt!(5.972168399240267e24));
+        let m = crate::Mass::from(Amnt!(1.988409870698051e30) * KILOGRAM);
+        assert_eq!(m.unit(), crate::SOLAR_MASS);
+        assert_almost_eq!(m.amount(), Amnt!(1));
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn test_duration() {
+        use quantities::duration::{Duration, HOUR, JULIAN_YEAR};
+
+        let d = Duration::from(Amnt!(2.5) * crate::JULIAN_YEAR);
+        assert_eq!(d.unit(), JULIAN_YEAR);
+        assert_almost_eq!(d.amount(), Amnt!(2.5));
+        let d = crate::Duration::from(Amnt!(36) * HOUR);
+        assert_eq!(d.unit(), crate::HOUR);
+        assert_almost_eq!(d.equiv_amount(crate::DAY), Amnt!(1.5));
+        let d = Duration::from(Amnt!(1) * crate::SIDERAL_DAY);
+        assert_almost_eq!(d.amount(), Amnt!(86164));
+    }
+
+    #[cfg(feature = "speed")]
+    #[test]
+    fn test_speed() {
+        use quantities::speed::{Speed, METER_PER_SECOND};
+
+        let v = Speed::from(Amnt!(1) * crate::SPEED_OF_LIGHT);
+        assert_eq!(v.unit(), METER_PER_SECOND);
+        assert_almost_eq!(v.amount(), Amnt!(299792458.));
+        let v = crate::Speed::from(Amnt!(29780) * METER_PER_SECOND);
+        assert_eq!(v.unit(), crate::METER_PER_SECOND);
+        assert_almost_eq!(
+            v.equiv_amount(crate::ASTRONOMICAL_UNITS_PER_DAY),
+            Amnt!(0.01719938918889973)
+        );
+    }
+
+    #[cfg(feature = "acceleration")]
+    #[test]
+    fn test_acceleration() {
+        use quantities::acceleration::{
+            Acceleration, METER_PER_SECOND_SQUARED,
+        };
+
+        let a = Amnt!(1) * crate::STANDARD_GRAVITY;
+        let si = Acceleration::from(a);
+        assert_almost_eq!(
+            si.equiv_amount(METER_PER_SECOND_SQUARED),
+            Amnt!(9.80665)
+        );
+        assert_eq!(
+            crate::Acceleration::from(si).unit(),
+            crate::METER_PER_SECOND_SQUARED
+        );
+    }
+
+    #[cfg(all(feature = "area", feature = "volume"))]
+    #[test]
+    fn test_area_and_volume() {
+        use quantities::{
+            area::{Area, SQUARE_METER},
+            volume::{Volume, CUBIC_METER},
+        };
+
+        let a = Area::from(Amnt!(1) * crate::SQUARE_KILOMETER);
+        assert_almost_eq!(a.equiv_amount(SQUARE_METER), Amnt!(1000000));
+        let v = Volume::from(Amnt!(1) * crate::CUBIC_KILOMETER);
+        assert_almost_eq!(v.equiv_amount(CUBIC_METER), Amnt!(1e9));
+        let v = crate::Volume::from(v);
+        assert_almost_eq!(v.equiv_amount(crate::CUBIC_KILOMETER), Amnt!(1));
+    }
+
+    #[cfg(feature = "density")]
+    #[test]
+    fn test_density() {
+        use quantities::density::{Density, GRAM_PER_CUBIC_CENTIMETER};
+
+        let d = Amnt!(1.41) * GRAM_PER_CUBIC_CENTIMETER;
+        let a = crate::Density::from(d);
+        assert_eq!(a.unit(), crate::GRAM_PER_CUBIC_CENTIMETER);
+        assert_almost_eq!(a.amount(), Amnt!(1.41));
+        let d = Density::from(
+            Amnt!(1) * crate::SOLAR_MASS_PER_CUBIC_ASTRONOMICAL_UNIT,
+        );
+        assert_almost_eq!(d.amount(), Amnt!(0.0005939223576917515));
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    fn test_luminosity() {
+        use quantities::power::{Power, GIGAWATT, WATT};
+
+        let p = Power::from(Amnt!(1) * crate::SOLAR_LUMINOSITY);
+        assert_eq!(p.unit(), WATT);
+        assert_almost_eq!(p.amount(), Amnt!(3.828e26));
+        let l = crate::Luminosity::from(Amnt!(3.828e17) * GIGAWATT);
+        assert_eq!(l.unit(), crate::SOLAR_LUMINOSITY);
+        assert_almost_eq!(l.amount(), Amnt!(1));
+    }
+
+    #[cfg(feature = "angle")]
+    #[test]
+    fn test_angle() {
+        use quantities::angle::{Angle, DEGREE, RADIAN};
+
+        let a = Angle::from(Amnt!(1) * crate::RADIAN);
+        assert_eq!(a.unit(), RADIAN);
+        assert_almost_eq!(a.amount(), Amnt!(1));
+        let a = crate::Angle::from(Amnt!(2.5) * DEGREE);
+        assert_eq!(a.unit(), crate::DEGREE);
+        assert_almost_eq!(a.equiv_amount(crate::ARCSECOND), Amnt!(9000));
+    }
+}
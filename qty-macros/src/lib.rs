@@ -185,6 +185,19 @@ pub fn derive_enum_iter(input: TokenStream) -> TokenStream {
 /// `#[unit(<ident>, "<symbol>", "<doc>")]`
 /// `#[unit(<ident>, "<symbol>")]`.
 ///
+/// To define a derived quantity, use one of the following forms of the
+/// quantity attribute
+///
+/// `#[quantity(<lhs_ident> * <rhs_ident>)]`
+/// `#[quantity(<lhs_ident> / <rhs_ident>)]`.
+///
+/// If the product or quotient already defines another quantity with the same
+/// dimension (for example `Energy` and `Torque`), add the argument
+/// `distinct_from = <qty_ident>`. In this case, no operator for building the
+/// product or quotient is generated. Instead, an associated function
+/// `from_product` or `from_quotient` is provided. The quantity given as
+/// `<qty_ident>` must be in scope.
+///
 /// # Panics
 ///
 /// The macro panics in the followong cases:
@@ -360,6 +373,17 @@ pub fn derive_enum_iter(input: TokenStream) -> TokenStream {
 ///         <Self as HasRefUnit>::div(self, rhs)
 ///     }
 /// }
+/// impl ::core::iter::Sum for Mass {
+///     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+///         iter.reduce(|acc, qty| acc + qty).unwrap_or_default()
+///     }
+/// }
+/// impl<'a> ::core::iter::Sum<&'a Self> for Mass {
+///     #[inline(always)]
+///     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+///         iter.copied().sum()
+///     }
+/// }
 /// #[doc = "0.001·g"]
 /// pub const MILLIGRAM: MassUnit = MassUnit::Milligram;
 /// #[doc = "0.001·kg"]
@@ -447,6 +471,20 @@ pub fn derive_enum_iter(input: TokenStream) -> TokenStream {
 ///         Self::Output::new(amnt * rhs.per_unit_multiple(), rhs.per_unit())
 ///     }
 /// }
+/// impl<TQ: Quantity> Mul<&Rate<TQ, Mass>> for &Mass {
+///     type Output = TQ;
+///     #[inline(always)]
+///     fn mul(self, rhs: &Rate<TQ, Mass>) -> Self::Output {
+///         *self * *rhs
+///     }
+/// }
+/// impl<PQ: Quantity> Div<&Rate<Mass, PQ>> for &Mass {
+///     type Output = PQ;
+///     #[inline(always)]
+///     fn div(self, rhs: &Rate<Mass, PQ>) -> Self::Output {
+///         *self / *rhs
+///     }
+/// }
 /// ```
 #[proc_macro_attribute]
 #[proc_macro_error]
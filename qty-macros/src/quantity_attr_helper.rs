@@ -11,11 +11,13 @@ use convert_case::{Case, Casing};
 use proc_macro_error2::{abort, abort_call_site};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use syn::{parse::Parser, punctuated::Punctuated};
 
 pub(crate) struct DerivedAs {
     lhs_ident: syn::Ident,
     op: syn::BinOp,
     rhs_ident: syn::Ident,
+    distinct_from: Option<syn::Ident>,
 }
 
 pub(crate) struct UnitDef {
@@ -55,40 +57,75 @@ fn get_ident(expr: &syn::Expr) -> Option<&syn::Ident> {
     }
 }
 
+fn parse_distinct_from(expr: &syn::Expr) -> Option<&syn::Ident> {
+    match expr {
+        syn::Expr::Assign(assign) => {
+            match get_ident(assign.left.as_ref()) {
+                Some(ident) if ident == "distinct_from" => {
+                    get_ident(assign.right.as_ref())
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn parse_args(args: TokenStream) -> Option<DerivedAs> {
     const ARGS_ERROR: &str =
         "Unknown argument(s) given to attribute `quantity`.";
     const OPERATOR_ERROR: &str = "Binary expression with '*' or '/' expected.";
     const OPERAND_ERROR: &str = "Identifier expected.";
+    const DISTINCT_FROM_ERROR: &str = "`distinct_from = <qty_ident>` expected.";
     #[rustfmt::skip]
     const ARGS_HELP: &str =
         "Use `#[quantity]`\n\
          or  `#[quantity(<lhs_ident> * <rhs_ident>]`\n\
-         or  `#[quantity(<lhs_ident> / <rhs_ident>]`.";
+         or  `#[quantity(<lhs_ident> / <rhs_ident>]`\n\
+         or  `#[quantity(<lhs_ident> * <rhs_ident>, \
+                         distinct_from = <qty_ident>]`\n\
+         or  `#[quantity(<lhs_ident> / <rhs_ident>, \
+                         distinct_from = <qty_ident>]`.";
 
     if args.is_empty() {
-        None
-    } else if let Ok(expr) = syn::parse2::<syn::Expr>(args) {
-        match expr {
-            syn::Expr::Binary(args) => match args.op {
-                syn::BinOp::Mul(_) | syn::BinOp::Div(_) => {
-                    let lhs = get_ident(args.left.as_ref());
-                    let rhs = get_ident(args.right.as_ref());
-                    if lhs.is_none() || rhs.is_none() {
-                        abort!(args, OPERAND_ERROR; help = ARGS_HELP)
-                    }
-                    Some(DerivedAs {
-                        lhs_ident: lhs.unwrap().clone(),
-                        op: args.op,
-                        rhs_ident: rhs.unwrap().clone(),
-                    })
-                }
-                _ => abort!(args, OPERATOR_ERROR; help = ARGS_HELP),
-            },
-            _ => abort!(expr, ARGS_ERROR; help = ARGS_HELP),
-        }
-    } else {
+        return None;
+    }
+    let parser = Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated;
+    let Ok(exprs) = parser.parse2(args) else {
+        abort_call_site!(ARGS_ERROR; help = ARGS_HELP)
+    };
+    let mut exprs = exprs.into_iter();
+    let Some(expr) = exprs.next() else {
         abort_call_site!(ARGS_ERROR; help = ARGS_HELP)
+    };
+    let distinct_from = match exprs.next() {
+        None => None,
+        Some(arg) => match parse_distinct_from(&arg) {
+            Some(ident) => Some(ident.clone()),
+            None => abort!(arg, DISTINCT_FROM_ERROR; help = ARGS_HELP),
+        },
+    };
+    if let Some(arg) = exprs.next() {
+        abort!(arg, ARGS_ERROR; help = ARGS_HELP)
+    }
+    match expr {
+        syn::Expr::Binary(args) => match args.op {
+            syn::BinOp::Mul(_) | syn::BinOp::Div(_) => {
+                let lhs = get_ident(args.left.as_ref());
+                let rhs = get_ident(args.right.as_ref());
+                if lhs.is_none() || rhs.is_none() {
+                    abort!(args, OPERAND_ERROR; help = ARGS_HELP)
+                }
+                Some(DerivedAs {
+                    lhs_ident: lhs.unwrap().clone(),
+                    op: args.op,
+                    rhs_ident: rhs.unwrap().clone(),
+                    distinct_from,
+                })
+            }
+            _ => abort!(args, OPERATOR_ERROR; help = ARGS_HELP),
+        },
+        _ => abort!(expr, ARGS_ERROR; help = ARGS_HELP),
     }
 }
 
@@ -335,7 +372,7 @@ pub(crate) fn analyze(item_ast: &mut Item) -> QtyDef {
         qty_def.units = unit_defs_without_scale_from_attrs(&unit_attrs);
         qty_def
             .units
-            .sort_by(|a, b| a.name.value().cmp(&b.name.value()));
+            .sort_by_key(|a| a.name.value());
     }
     qty_def
 }
@@ -427,6 +464,10 @@ fn codegen_qty_single_unit(
 ) -> TokenStream {
     let serde_derives = codegen_serde_derives();
     let unit_doc = format!("Unit of quantity `{}`.", qty_ident);
+    let fn_new_doc = format!(
+        "Creates a new `{}` value with the given amount and unit.",
+        qty_ident
+    );
     quote!(
         #[doc = #unit_doc]
         #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -452,6 +493,10 @@ fn codegen_qty_single_unit(
             amount: AmountT
         }
         impl #qty_ident {
+            #[doc = #fn_new_doc]
+            pub const fn new(amount: AmountT, _unit: #unit_enum_ident) -> Self {
+                Self { amount }
+            }
             fn abs(&self) -> Self {
                 Self { amount: self.amount.abs() }
             }
@@ -632,6 +677,10 @@ fn codegen_impl_quantity(
             unit: #unit_enum_ident
         }
         impl #qty_ident {
+            #[doc = #fn_new_doc]
+            pub const fn new(amount: AmountT, unit: #unit_enum_ident) -> Self {
+                Self { amount, unit }
+            }
             #[doc = #fn_abs_doc]
             pub fn abs(&self) -> Self {
                 Self { amount: self.amount.abs(), unit: self.unit }
@@ -675,6 +724,15 @@ fn codegen_qty_without_ref_unit(
     let serde_derives = codegen_serde_derives();
     let unit_doc = format!("Unit of quantity `{}`.", qty_ident);
     let code_impl_quantity = codegen_impl_quantity(qty_ident, unit_enum_ident);
+    let fn_try_sum_doc = format!(
+        "Returns the sum of the given `{}` values, or `None` if there are \
+        none.\n\n\
+        As `{}` has no reference unit, there is no neutral element to \
+        return for an empty sequence, so `Sum` is not implemented.\n\n\
+        # Panics\n\n\
+        Panics if the values have different units.",
+        qty_ident, qty_ident
+    );
     quote!(
         #code_impl_quantity
         #[doc = #unit_doc]
@@ -737,6 +795,14 @@ fn codegen_qty_without_ref_unit(
                 <Self as Quantity>::div(self, rhs)
             }
         }
+        impl #qty_ident {
+            #[doc = #fn_try_sum_doc]
+            pub fn try_sum<I: IntoIterator<Item = Self>>(
+                qties: I,
+            ) -> Option<Self> {
+                qties.into_iter().reduce(|acc, qty| acc + qty)
+            }
+        }
     )
 }
 
@@ -885,6 +951,17 @@ fn codegen_qty_with_ref_unit(
                 <Self as HasRefUnit>::div(self, rhs)
             }
         }
+        impl ::core::iter::Sum for #qty_ident {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.reduce(|acc, qty| acc + qty).unwrap_or_default()
+            }
+        }
+        impl<'a> ::core::iter::Sum<&'a Self> for #qty_ident {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
     )
 }
 
@@ -957,6 +1034,20 @@ fn codegen_impl_std_traits(qty_ident: &syn::Ident) -> TokenStream {
                 )
             }
         }
+        impl<TQ: Quantity> Mul<&Rate<TQ, #qty_ident>> for &#qty_ident {
+            type Output = TQ;
+            #[inline(always)]
+            fn mul(self, rhs: &Rate<TQ, #qty_ident>) -> Self::Output {
+                *self * *rhs
+            }
+        }
+        impl<PQ: Quantity> Div<&Rate<#qty_ident, PQ>> for &#qty_ident {
+            type Output = PQ;
+            #[inline(always)]
+            fn div(self, rhs: &Rate<#qty_ident, PQ>) -> Self::Output {
+                *self / *rhs
+            }
+        }
     )
 }
 
@@ -1160,6 +1251,48 @@ fn codegen_impl_div_qties(
     )
 }
 
+fn codegen_fn_from_derived_as(
+    qty_ident: &syn::Ident,
+    derived_as: &DerivedAs,
+    distinct_from: &syn::Ident,
+) -> TokenStream {
+    let lhs_qty_ident = &derived_as.lhs_ident;
+    let rhs_qty_ident = &derived_as.rhs_ident;
+    let (fn_ident, op, op_name) = match derived_as.op {
+        syn::BinOp::Mul(_) => ("from_product", quote!(*), "product"),
+        _ => ("from_quotient", quote!(/), "quotient"),
+    };
+    let fn_ident = syn::Ident::new(fn_ident, Span::call_site());
+    let fn_doc = format!(
+        "Returns the {op_name} of `lhs` and `rhs` as `{qty_ident}`.\n\n\
+         The operator `{lhs_qty_ident} {op} {rhs_qty_ident}` results in \
+         `{distinct_from}`, which has the same dimension as \
+         `{qty_ident}`, but is a distinct quantity."
+    );
+    quote!(
+        // Make sure `distinct_from` names an existing type.
+        const _: fn() = || {
+            let _: Option<#distinct_from> = None;
+        };
+        impl #qty_ident {
+            #[doc = #fn_doc]
+            pub fn #fn_ident(
+                lhs: #lhs_qty_ident,
+                rhs: #rhs_qty_ident,
+            ) -> Self {
+                let scale = lhs.unit().scale() #op rhs.unit().scale();
+                match <Self as HasRefUnit>::unit_from_scale(scale) {
+                    Some(unit) =>
+                        Self::new(lhs.amount() #op rhs.amount(), unit),
+                    None => <Self as HasRefUnit>::_fit(
+                        (lhs.amount() #op rhs.amount()) * scale
+                    ),
+                }
+            }
+        }
+    )
+}
+
 fn codegen_impl_mul_div_qties(
     qty_ident: &syn::Ident,
     derived_as: &Option<DerivedAs>,
@@ -1171,11 +1304,18 @@ fn codegen_impl_mul_div_qties(
             let rhs_qty_ident = &derived_as.rhs_ident;
             match derived_as.op {
                 syn::BinOp::Mul(_) => {
-                    let code_impl_mul = codegen_impl_mul_qties(
-                        qty_ident,
-                        lhs_qty_ident,
-                        rhs_qty_ident,
-                    );
+                    let code_impl_mul = match &derived_as.distinct_from {
+                        None => codegen_impl_mul_qties(
+                            qty_ident,
+                            lhs_qty_ident,
+                            rhs_qty_ident,
+                        ),
+                        Some(distinct_from) => codegen_fn_from_derived_as(
+                            qty_ident,
+                            derived_as,
+                            distinct_from,
+                        ),
+                    };
                     let code_impl_res_div_rhs = codegen_impl_div_qties(
                         lhs_qty_ident,
                         qty_ident,
@@ -1198,11 +1338,18 @@ fn codegen_impl_mul_div_qties(
                     )
                 }
                 syn::BinOp::Div(_) => {
-                    let code_impl_div = codegen_impl_div_qties(
-                        qty_ident,
-                        lhs_qty_ident,
-                        rhs_qty_ident,
-                    );
+                    let code_impl_div = match &derived_as.distinct_from {
+                        None => codegen_impl_div_qties(
+                            qty_ident,
+                            lhs_qty_ident,
+                            rhs_qty_ident,
+                        ),
+                        Some(distinct_from) => codegen_fn_from_derived_as(
+                            qty_ident,
+                            derived_as,
+                            distinct_from,
+                        ),
+                    };
                     let code_impl_mul_res = codegen_impl_mul_qties(
                         lhs_qty_ident,
                         qty_ident,
@@ -1408,8 +1555,19 @@ mod internal_fn_tests {
         assert!(matches!(derived_as.op, syn::BinOp::Mul(_)));
         assert_eq!(derived_as.lhs_ident.to_string(), "Foo");
         assert_eq!(derived_as.rhs_ident.to_string(), "Foo");
+        assert!(derived_as.distinct_from.is_none());
         assert_eq!(item.ident.to_string(), "FooSquared");
         assert!(item.fields.is_empty());
         assert_eq!(item.attrs.len(), 4);
     }
+
+    #[test]
+    fn test_parse_derived_qty_distinct_from() {
+        let args = quote!(Foo / Bar, distinct_from = Baz);
+        let derived_as = parse_args(args).unwrap();
+        assert!(matches!(derived_as.op, syn::BinOp::Div(_)));
+        assert_eq!(derived_as.lhs_ident.to_string(), "Foo");
+        assert_eq!(derived_as.rhs_ident.to_string(), "Bar");
+        assert_eq!(derived_as.distinct_from.unwrap().to_string(), "Baz");
+    }
 }
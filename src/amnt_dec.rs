@@ -7,6 +7,7 @@
 // $Source$
 // $Revision$
 
+use fpdec::{CheckedAdd, CheckedMul, Round};
 pub use fpdec::{Dec, Decimal};
 
 /// Type used for the numerical part of a Quantity.
@@ -27,6 +28,47 @@ pub const AMNT_ZERO: AmountT = Decimal::ZERO;
 /// AmountT constant equal 1
 pub const AMNT_ONE: AmountT = Decimal::ONE;
 
+/// Converts `amnt` to `f64`.
+#[inline(always)]
+pub(crate) fn amnt_to_f64(amnt: AmountT) -> f64 {
+    f64::from(amnt)
+}
+
+/// Converts `f` to `AmountT`, returning `None` if `f` is not finite or out
+/// of the range of `AmountT`.
+#[inline(always)]
+pub(crate) fn amnt_from_f64(f: f64) -> Option<AmountT> {
+    AmountT::try_from(f).ok()
+}
+
+/// Converts `n` to `AmountT`.
+#[inline(always)]
+pub(crate) fn amnt_from_usize(n: usize) -> AmountT {
+    AmountT::from(n as u64)
+}
+
+/// Returns `lhs` + `rhs`, or `None` if the result can't be represented as
+/// `AmountT`.
+#[inline(always)]
+pub(crate) fn amnt_checked_add(lhs: AmountT, rhs: AmountT) -> Option<AmountT> {
+    lhs.checked_add(rhs)
+}
+
+/// Returns `lhs` * `rhs`, or `None` if the result can't be represented as
+/// `AmountT`.
+#[inline(always)]
+pub(crate) fn amnt_checked_mul(lhs: AmountT, rhs: AmountT) -> Option<AmountT> {
+    lhs.checked_mul(rhs)
+}
+
+/// Returns `amnt` rounded to `n_frac_digits` fractional digits, according to
+/// the current rounding mode of crate `fpdec` (default: ties to even).
+#[allow(clippy::cast_possible_wrap)]
+#[inline(always)]
+pub(crate) fn amnt_round(amnt: AmountT, n_frac_digits: u8) -> AmountT {
+    amnt.round(n_frac_digits as i8)
+}
+
 #[allow(non_snake_case)]
 #[macro_export]
 /// Converts a numeric literal to an `AmountT`.
@@ -25,6 +25,60 @@ pub const AMNT_ZERO: AmountT = 0.;
 /// AmountT constant equal 1
 pub const AMNT_ONE: AmountT = 1.;
 
+/// Converts `amnt` to `f64`.
+#[inline(always)]
+pub(crate) const fn amnt_to_f64(amnt: AmountT) -> f64 {
+    amnt
+}
+
+/// Converts `f` to `AmountT`, returning `None` if `f` is not finite.
+#[inline(always)]
+pub(crate) fn amnt_from_f64(f: f64) -> Option<AmountT> {
+    f.is_finite().then_some(f)
+}
+
+/// Converts `n` to `AmountT`; the result is exact for `n` < 2⁵³.
+#[allow(clippy::cast_precision_loss)]
+#[inline(always)]
+pub(crate) const fn amnt_from_usize(n: usize) -> AmountT {
+    n as AmountT
+}
+
+/// Returns `lhs` + `rhs`, or `None` if the result is not finite.
+#[inline(always)]
+pub(crate) fn amnt_checked_add(lhs: AmountT, rhs: AmountT) -> Option<AmountT> {
+    let res = lhs + rhs;
+    res.is_finite().then_some(res)
+}
+
+/// Returns `lhs` * `rhs`, or `None` if the result is not finite.
+#[inline(always)]
+pub(crate) fn amnt_checked_mul(lhs: AmountT, rhs: AmountT) -> Option<AmountT> {
+    let res = lhs * rhs;
+    res.is_finite().then_some(res)
+}
+
+/// Returns `amnt` rounded to `n_frac_digits` fractional digits, with ties
+/// rounded to even.
+pub(crate) fn amnt_round(amnt: AmountT, n_frac_digits: u8) -> AmountT {
+    // Adding and subtracting 2^52 rounds to an integer (ties to even) any
+    // value with an absolute value less than 2^52; greater values don't
+    // have fractional digits.
+    const LIMIT: AmountT = 4_503_599_627_370_496.;
+    let mut scale = AMNT_ONE;
+    for _ in 0..n_frac_digits {
+        scale *= 10.;
+    }
+    let scaled = amnt * scale;
+    if scaled >= LIMIT || scaled <= -LIMIT {
+        amnt
+    } else if scaled >= AMNT_ZERO {
+        (scaled + LIMIT - LIMIT) / scale
+    } else {
+        (scaled - LIMIT + LIMIT) / scale
+    }
+}
+
 #[allow(non_snake_case)]
 #[macro_export]
 /// Converts a numeric literal to an `AmountT`.
@@ -0,0 +1,61 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of basic quantity `AmountOfSubstance`.
+
+use crate::prelude::*;
+
+#[quantity]
+#[ref_unit(
+    Mole,
+    "mol",
+    NONE,
+    "Reference unit of quantity `AmountOfSubstance`"
+)]
+#[unit(Nanomole, "nmol", NANO, 0.000000001, "0.000000001·mol")]
+#[unit(Micromole, "µmol", MICRO, 0.000001, "0.000001·mol")]
+#[unit(Millimole, "mmol", MILLI, 0.001, "0.001·mol")]
+#[unit(Kilomole, "kmol", KILO, 1000, "1000·mol")]
+/// Number of elementary entities (atoms, molecules, ions, …) in a sample,
+/// measured in multiples of the Avogadro number
+///
+/// Reference unit: Mole ('mol')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'mol' |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | nmol   | Nanomole              | 0.000000001·mol   | 0.000000001         |
+/// | µmol   | Micromole             | 0.000001·mol      | 0.000001            |
+/// | mmol   | Millimole             | 0.001·mol         | 0.001               |
+/// | kmol   | Kilomole              | 1000·mol          | 1000                |
+pub struct AmountOfSubstance {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_almost_eq;
+
+    #[test]
+    fn test_amount_of_substance() {
+        assert_eq!(
+            <AmountOfSubstance as HasRefUnit>::REF_UNIT,
+            AmountOfSubstanceUnit::REF_UNIT
+        );
+        assert!(MOLE.is_ref_unit());
+        let amnt: AmountT = Amnt!(12.5);
+        let n = amnt * MILLIMOLE;
+        assert_eq!(n.amount, amnt);
+        assert_eq!(n.unit, MILLIMOLE);
+        #[cfg(feature = "std")]
+        assert_eq!(n.to_string(), "12.5 mmol");
+        let n = n.convert(MICROMOLE);
+        assert_almost_eq!(n.amount, amnt * Amnt!(1000.));
+    }
+}
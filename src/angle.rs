@@ -0,0 +1,146 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of basic quantity `Angle`.
+
+// Unit scales must be given as literals, so the constants from
+// `core::f64::consts` can't be used.
+#![allow(clippy::approx_constant)]
+
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::{amnt_from_f64, amnt_to_f64};
+
+#[quantity]
+#[ref_unit(Radian, "rad", NONE, "Reference unit of quantity `Angle`")]
+#[unit(Arcsecond, "″", 0.000004848136811095, "1/60·′")]
+#[unit(Arcminute, "′", 0.000290888208665722, "1/60·°")]
+#[unit(Milliradian, "mrad", MILLI, 0.001, "0.001·rad")]
+#[unit(Gon, "gon", 0.015707963267948967, "π/200·rad")]
+#[unit(Degree, "°", 0.017453292519943295, "π/180·rad")]
+#[unit(Revolution, "rev", 6.283185307179586, "2π·rad")]
+/// Figure formed by two rays sharing a common endpoint
+///
+/// Reference unit: Radian ('rad')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition | Equiv. in 'rad'      |
+/// |--------|-----------------------|------------|----------------------|
+/// | ″      | Arcsecond             | 1/60·′     | 0.000004848136811095 |
+/// | ′      | Arcminute             | 1/60·°     | 0.000290888208665722 |
+/// | mrad   | Milliradian           | 0.001·rad  | 0.001                |
+/// | gon    | Gon                   | π/200·rad  | 0.015707963267948967 |
+/// | °      | Degree                | π/180·rad  | 0.017453292519943295 |
+/// | rev    | Revolution            | 2π·rad     | 6.283185307179586    |
+pub struct Angle {}
+
+// `AmountT` is already marked as `must_use` if feature `fpdec` is enabled.
+#[cfg(feature = "std")]
+#[allow(clippy::multiple_inherent_impl, clippy::must_use_candidate)]
+impl Angle {
+    /// Returns the sine of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can not be represented as `AmountT`.
+    pub fn sin(&self) -> AmountT {
+        Self::amnt_from_f64(self.as_f64().sin())
+    }
+
+    /// Returns the cosine of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can not be represented as `AmountT`.
+    pub fn cos(&self) -> AmountT {
+        Self::amnt_from_f64(self.as_f64().cos())
+    }
+
+    /// Returns the tangent of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can not be represented as `AmountT`.
+    pub fn tan(&self) -> AmountT {
+        Self::amnt_from_f64(self.as_f64().tan())
+    }
+
+    /// Returns the angle (in radians) between the positive x-axis and the
+    /// ray from the origin to the point (`x`, `y`), i.e. the four quadrant
+    /// arctangent of `y` / `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can not be represented as `AmountT`.
+    #[must_use]
+    pub fn atan2(y: AmountT, x: AmountT) -> Self {
+        let rad = amnt_to_f64(y).atan2(amnt_to_f64(x));
+        Self::new(Self::amnt_from_f64(rad), RADIAN)
+    }
+
+    #[inline(always)]
+    fn as_f64(&self) -> f64 {
+        amnt_to_f64(self.equiv_amount(RADIAN))
+    }
+
+    #[inline(always)]
+    fn amnt_from_f64(f: f64) -> AmountT {
+        match amnt_from_f64(f) {
+            Some(amnt) => amnt,
+            None => panic!("Result not representable as AmountT: {f}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{amnt_round, assert_almost_eq, AMNT_ONE};
+
+    #[test]
+    fn test_angle() {
+        assert_eq!(<Angle as HasRefUnit>::REF_UNIT, AngleUnit::REF_UNIT);
+        assert!(RADIAN.is_ref_unit());
+        let amnt: AmountT = Amnt!(29.35);
+        let a = amnt * DEGREE;
+        assert_eq!(a.amount, amnt);
+        assert_eq!(a.unit, DEGREE);
+        #[cfg(feature = "std")]
+        assert_eq!(a.to_string(), "29.35 °");
+        let a = Amnt!(0.25) * REVOLUTION;
+        assert_almost_eq!(a.convert(DEGREE).amount(), Amnt!(90.));
+        assert_almost_eq!(a.convert(GON).amount(), Amnt!(100.));
+        // scales of ′ and ″ are rounded to 18 fractional digits
+        let a = (Amnt!(1.5) * DEGREE).convert(ARCMINUTE);
+        assert_eq!(amnt_round(a.amount(), 4), Amnt!(90.));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_trigonometric_functions() {
+        let a = Amnt!(30.) * DEGREE;
+        assert_almost_eq!(a.sin(), Amnt!(0.5));
+        let a = Amnt!(100.) * GON;
+        // cos(π/2) is not exactly 0, so compare 1 + cos(π/2) with 1
+        assert_almost_eq!(a.cos() + AMNT_ONE, AMNT_ONE);
+        let a = Amnt!(0.125) * REVOLUTION;
+        assert_almost_eq!(a.tan(), Amnt!(1.));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_atan2() {
+        let a = Angle::atan2(Amnt!(1.), Amnt!(-1.));
+        assert_eq!(a.unit(), RADIAN);
+        assert_almost_eq!(a.convert(DEGREE).amount(), Amnt!(135.));
+        let a = Angle::atan2(Amnt!(-2.), Amnt!(0));
+        assert_almost_eq!(a.convert(DEGREE).amount(), Amnt!(-90.));
+    }
+}
@@ -0,0 +1,97 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `AngularVelocity`.
+
+// Unit scales must be given as literals, so the constants from
+// `core::f64::consts` can't be used.
+#![allow(clippy::approx_constant)]
+
+use crate::{angle::Angle, duration::Duration, prelude::*};
+#[cfg(feature = "frequency")]
+use crate::{frequency::Frequency, ProportionalEquivalence};
+
+#[quantity(Angle / Duration)]
+#[ref_unit(
+    Radian_per_Second,
+    "rad/s",
+    NONE,
+    "Reference unit of quantity `AngularVelocity`"
+)]
+#[unit(Degree_per_Second, "°/s", 0.017453292519943295, "°/s")]
+#[unit(Revolution_per_Minute, "rpm", 0.10471975511965977, "rev/min")]
+#[unit(Revolution_per_Second, "rev/s", 6.283185307179586, "rev/s")]
+/// Change of an angle per unit of time
+///
+/// Definition: Angle/Duration
+///
+/// Reference unit: Radian per Second ('rad/s')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition | Equiv. in 'rad/s'    |
+/// |--------|-----------------------|------------|----------------------|
+/// | °/s    | Degree per Second     | °/s        | 0.017453292519943295 |
+/// | rpm    | Revolution per Minute | rev/min    | 0.10471975511965977  |
+/// | rev/s  | Revolution per Second | rev/s      | 6.283185307179586    |
+pub struct AngularVelocity {}
+
+/// Equivalence of angular velocity and frequency of rotation: f = ω / 2π
+#[cfg(feature = "frequency")]
+pub const ANGULAR_VELOCITY_FREQUENCY: ProportionalEquivalence<
+    AngularVelocity,
+    Frequency,
+> = ProportionalEquivalence::new(Amnt!(0.15915494309189535));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        angle::{DEGREE, REVOLUTION},
+        assert_almost_eq,
+        duration::{MINUTE, SECOND},
+    };
+
+    #[test]
+    fn test_angle_div_duration() {
+        let aa: AmountT = Amnt!(3000.);
+        let a = aa * REVOLUTION;
+        let at: AmountT = Amnt!(2.);
+        let t = at * MINUTE;
+        let w = a / t;
+        assert_almost_eq!(w.equiv_amount(REVOLUTION_PER_MINUTE), aa / at);
+        let w = w.convert(RADIAN_PER_SECOND);
+        assert_almost_eq!(w.amount(), Amnt!(157.07963267948966));
+    }
+
+    #[test]
+    fn test_angular_velocity_mul_duration() {
+        let aw: AmountT = Amnt!(15.);
+        let w = aw * DEGREE_PER_SECOND;
+        let at: AmountT = Amnt!(6.);
+        let t = at * SECOND;
+        let a = w * t;
+        assert_almost_eq!(a.amount(), aw * at);
+        assert_eq!(a.unit(), DEGREE);
+    }
+
+    #[cfg(feature = "frequency")]
+    #[test]
+    fn test_angular_velocity_frequency_equivalence() {
+        use crate::{frequency::HERTZ, Equivalence};
+        let w = Amnt!(1500.) * REVOLUTION_PER_MINUTE;
+        let f = ANGULAR_VELOCITY_FREQUENCY.convert(&w, HERTZ).unwrap();
+        assert_almost_eq!(f.amount(), Amnt!(25.));
+        assert_eq!(f.unit(), HERTZ);
+        let w = ANGULAR_VELOCITY_FREQUENCY
+            .convert_back(&f, REVOLUTION_PER_MINUTE)
+            .unwrap();
+        assert_almost_eq!(w.amount(), Amnt!(1500.));
+    }
+}
@@ -0,0 +1,69 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Capacitance`.
+
+use crate::{electric_charge::ElectricCharge, prelude::*, voltage::Voltage};
+
+#[quantity(ElectricCharge / Voltage)]
+#[ref_unit(Farad, "F", NONE, "Reference unit of quantity `Capacitance`")]
+#[unit(Picofarad, "pF", PICO, 0.000000000001, "0.000000000001·F")]
+#[unit(Nanofarad, "nF", NANO, 0.000000001, "0.000000001·F")]
+#[unit(Microfarad, "µF", MICRO, 0.000001, "0.000001·F")]
+#[unit(Millifarad, "mF", MILLI, 0.001, "0.001·F")]
+/// Ability of a body to store electric charge
+///
+/// Definition: ElectricCharge/Voltage
+///
+/// Reference unit: Farad ('F' = 'C/V' = 's⁴·A²/(kg·m²)')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'F'   |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | pF     | Picofarad             | 0.000000000001·F  | 0.000000000001      |
+/// | nF     | Nanofarad             | 0.000000001·F     | 0.000000001         |
+/// | µF     | Microfarad            | 0.000001·F        | 0.000001            |
+/// | mF     | Millifarad            | 0.001·F           | 0.001               |
+pub struct Capacitance {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        electric_charge::{MICROCOULOMB, MILLICOULOMB},
+        voltage::VOLT,
+    };
+
+    #[test]
+    fn test_charge_div_voltage() {
+        let aq: AmountT = Amnt!(47.);
+        let q = aq * MICROCOULOMB;
+        let au: AmountT = Amnt!(10.);
+        let u = au * VOLT;
+        let c = q / u;
+        assert_almost_eq!(c.amount(), aq / au);
+        assert_eq!(c.unit(), MICROFARAD);
+    }
+
+    #[test]
+    fn test_capacitance_mul_voltage() {
+        let ac: AmountT = Amnt!(2.2);
+        let c = ac * MILLIFARAD;
+        let au: AmountT = Amnt!(12.);
+        let u = au * VOLT;
+        let q = c * u;
+        assert_almost_eq!(q.amount(), ac * au);
+        assert_eq!(q.unit(), MILLICOULOMB);
+        let u = q / c;
+        assert_almost_eq!(u.amount(), au);
+        assert_eq!(u.unit(), VOLT);
+    }
+}
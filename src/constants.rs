@@ -0,0 +1,176 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Physical constants expressed as quantities or rates.
+//!
+//! Each constant is available if the features providing the quantities it
+//! is defined by are enabled. The values are the exact values defining the
+//! SI units or the conventional values fixed by the CGPM.
+//!
+//! All variants of `AmountT` can represent large amounts like the one of
+//! the Avogadro constant, but not all of them very small ones. Constants
+//! with very small amounts are therefore given as `Rate` with a large
+//! per-amount.
+
+#[cfg(feature = "acceleration")]
+use crate::acceleration::{Acceleration, METER_PER_SECOND_SQUARED};
+#[cfg(feature = "amount_of_substance")]
+use crate::amount_of_substance::{AmountOfSubstance, MOLE};
+#[cfg(feature = "electric_charge")]
+use crate::electric_charge::{ElectricCharge, COULOMB};
+#[cfg(all(
+    feature = "energy",
+    any(feature = "frequency", feature = "temperature")
+))]
+use crate::energy::{Energy, JOULE};
+#[cfg(feature = "frequency")]
+use crate::frequency::{Frequency, HERTZ};
+#[cfg(feature = "pressure")]
+use crate::pressure::{Pressure, PASCAL};
+#[cfg(feature = "speed")]
+use crate::speed::{Speed, METER_PER_SECOND};
+#[cfg(all(feature = "energy", feature = "temperature"))]
+use crate::temperature::{Temperature, KELVIN};
+#[cfg(any(
+    feature = "speed",
+    feature = "frequency",
+    feature = "electric_charge",
+    feature = "amount_of_substance"
+))]
+use crate::Amnt;
+#[cfg(any(
+    all(
+        feature = "energy",
+        any(feature = "frequency", feature = "temperature")
+    ),
+    feature = "electric_charge",
+    feature = "amount_of_substance"
+))]
+use crate::Rate;
+#[cfg(any(feature = "electric_charge", feature = "amount_of_substance"))]
+use crate::{AmountT, ONE};
+#[cfg(all(
+    feature = "fpdec",
+    any(
+        feature = "speed",
+        feature = "frequency",
+        feature = "electric_charge",
+        feature = "amount_of_substance"
+    )
+))]
+use crate::{Dec, Decimal};
+
+/// Speed of light in vacuum: c = 299792458 m/s
+#[cfg(feature = "speed")]
+pub const SPEED_OF_LIGHT: Speed =
+    Speed::new(Amnt!(299792458), METER_PER_SECOND);
+
+/// Standard acceleration of gravity: gₙ = 9.80665 m/s²
+#[cfg(feature = "acceleration")]
+pub const STANDARD_GRAVITY: Acceleration =
+    Acceleration::new(Amnt!(9.80665), METER_PER_SECOND_SQUARED);
+
+/// Standard atmosphere: atm = 101325 Pa
+#[cfg(feature = "pressure")]
+pub const STANDARD_ATMOSPHERE: Pressure = Pressure::new(Amnt!(101325), PASCAL);
+
+/// Boltzmann constant: k = 1.380649·10⁻²³ J/K
+#[cfg(all(feature = "energy", feature = "temperature"))]
+pub const BOLTZMANN_CONSTANT: Rate<Energy, Temperature> =
+    Rate::new(Amnt!(1.380649), JOULE, Amnt!(1e23), KELVIN);
+
+/// Planck constant: h = 6.62607015·10⁻³⁴ J/Hz
+#[cfg(all(feature = "energy", feature = "frequency"))]
+pub const PLANCK_CONSTANT: Rate<Energy, Frequency> =
+    Rate::new(Amnt!(6.62607015), JOULE, Amnt!(1e34), HERTZ);
+
+/// Hyperfine transition frequency of caesium-133: Δν(Cs) = 9192631770 Hz
+#[cfg(feature = "frequency")]
+pub const CAESIUM_HYPERFINE_FREQUENCY: Frequency =
+    Frequency::new(Amnt!(9192631770.), HERTZ);
+
+/// Elementary charge: e = 1.602176634·10⁻¹⁹ C
+#[cfg(feature = "electric_charge")]
+pub const ELEMENTARY_CHARGE: Rate<ElectricCharge, AmountT> =
+    Rate::new(Amnt!(1.602176634), COULOMB, Amnt!(1e19), ONE);
+
+/// Avogadro constant: Nₐ = 6.02214076·10²³ mol⁻¹
+#[cfg(feature = "amount_of_substance")]
+pub const AVOGADRO_CONSTANT: Rate<AmountT, AmountOfSubstance> =
+    Rate::new(Amnt!(6.02214076e23), ONE, Amnt!(1), MOLE);
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(
+        feature = "speed",
+        feature = "electric_charge",
+        feature = "amount_of_substance"
+    ))]
+    use super::*;
+    #[cfg(any(
+        feature = "speed",
+        feature = "electric_charge",
+        feature = "amount_of_substance"
+    ))]
+    use crate::{assert_almost_eq, prelude::*};
+
+    #[cfg(feature = "speed")]
+    #[test]
+    fn test_speed_of_light() {
+        use crate::speed::KILOMETER_PER_HOUR;
+        assert_eq!(SPEED_OF_LIGHT.unit(), METER_PER_SECOND);
+        let c = SPEED_OF_LIGHT.convert(KILOMETER_PER_HOUR);
+        assert_almost_eq!(c.amount(), Amnt!(1079252848.8));
+    }
+
+    #[cfg(feature = "pressure")]
+    #[test]
+    fn test_standard_atmosphere() {
+        use crate::pressure::{ATMOSPHERE, BAR};
+        assert_eq!(STANDARD_ATMOSPHERE, Amnt!(1) * ATMOSPHERE);
+        let p = STANDARD_ATMOSPHERE.convert(BAR);
+        assert_almost_eq!(p.amount(), Amnt!(1.01325));
+    }
+
+    #[cfg(all(feature = "energy", feature = "temperature"))]
+    #[test]
+    fn test_boltzmann_constant() {
+        let t = Amnt!(3e25) * KELVIN;
+        let e = BOLTZMANN_CONSTANT * t;
+        assert_eq!(e.unit(), JOULE);
+        assert_almost_eq!(e.amount(), Amnt!(414.1947));
+    }
+
+    #[cfg(all(feature = "energy", feature = "frequency"))]
+    #[test]
+    fn test_planck_constant() {
+        let f = Amnt!(1e25) * CAESIUM_HYPERFINE_FREQUENCY;
+        let e = PLANCK_CONSTANT * f;
+        assert_eq!(e.unit(), JOULE);
+        assert_almost_eq!(e.amount(), Amnt!(60.91102297113867));
+    }
+
+    #[cfg(feature = "electric_charge")]
+    #[test]
+    fn test_elementary_charge() {
+        let n = Amnt!(5e18);
+        let q = ELEMENTARY_CHARGE * n;
+        assert_eq!(q.unit(), COULOMB);
+        assert_almost_eq!(q.amount(), Amnt!(0.801088317));
+    }
+
+    #[cfg(feature = "amount_of_substance")]
+    #[test]
+    fn test_avogadro_constant() {
+        let n = Amnt!(2.5) * MOLE;
+        let c = AVOGADRO_CONSTANT * n;
+        assert_eq!(c.unit(), ONE);
+        assert_almost_eq!(c, Amnt!(1.50553519e24));
+    }
+}
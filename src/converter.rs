@@ -7,13 +7,79 @@
 // $Source$
 // $Revision$
 
-use crate::{AmountT, Quantity};
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{
+    AmountT, HasRefUnit, LinearScaledUnit, Quantity, AMNT_ONE, AMNT_ZERO,
+};
 
 /// Trait for quantity converters
 pub trait Converter<Q: Quantity> {
     /// Returns `conv` where `conv` ≣ `qty` and `conv.unit()` is `to_unit`, or
     /// `None` if conversion is not possible.
-    fn convert(self, qty: &Q, to_unit: Q::UnitType) -> Option<Q>;
+    fn convert(&self, qty: &Q, to_unit: Q::UnitType) -> Option<Q>;
+}
+
+impl<Q: Quantity, C: Converter<Q>> Converter<Q> for &C {
+    #[inline(always)]
+    fn convert(&self, qty: &Q, to_unit: Q::UnitType) -> Option<Q> {
+        (*self).convert(qty, to_unit)
+    }
+}
+
+/// Returns `(factor, offset)` so that
+/// to_amount = from_amount * factor + offset
+/// defines the conversion from `from_unit` to `to_unit`, or `None` if there
+/// is no such conversion derivable from the given mappings.
+///
+/// A mapping given for (from_unit, to_unit) takes precedence. Otherwise the
+/// mappings are searched breadth-first for a chain of conversions, where each
+/// mapping can be used in both directions, except for a mapping with a zero
+/// factor, which is not invertible.
+pub(crate) fn find_conversion<U: PartialEq + Copy>(
+    mappings: &[(U, U, AmountT, AmountT)],
+    from_unit: U,
+    to_unit: U,
+) -> Option<(AmountT, AmountT)> {
+    if from_unit == to_unit {
+        return Some((AMNT_ONE, AMNT_ZERO));
+    }
+    let direct = mappings.iter().find_map(|(from, to, factor, offset)| {
+        (*from == from_unit && *to == to_unit).then_some((*factor, *offset))
+    });
+    if direct.is_some() {
+        return direct;
+    }
+    // Each entry holds a reached unit together with the accumulated
+    // conversion from `from_unit` to that unit.
+    let mut reached: Vec<(U, AmountT, AmountT)> =
+        vec![(from_unit, AMNT_ONE, AMNT_ZERO)];
+    let mut idx = 0;
+    while idx < reached.len() {
+        let (unit, acc_factor, acc_offset) = reached[idx];
+        for (from, to, factor, offset) in mappings {
+            let step = if *from == unit {
+                (*to, *factor, *offset)
+            } else if *to == unit && *factor != AMNT_ZERO {
+                // inverse of to_amount = from_amount * factor + offset
+                (*from, AMNT_ONE / *factor, -*offset / *factor)
+            } else {
+                continue;
+            };
+            if reached.iter().any(|(u, _, _)| *u == step.0) {
+                continue;
+            }
+            let next =
+                (step.0, acc_factor * step.1, acc_offset * step.1 + step.2);
+            if next.0 == to_unit {
+                return Some((next.1, next.2));
+            }
+            reached.push(next);
+        }
+        idx += 1;
+    }
+    None
 }
 
 /// A table defining the conversion between instances of quantity `Q` having
@@ -27,6 +93,12 @@ pub trait Converter<Q: Quantity> {
 ///
 /// defining the conversion
 /// to_amount = from_amount * factor + offset
+///
+/// The inverse conversion from to_unit to from_unit is derived from the
+/// entry (unless its factor is zero), and conversions between units not
+/// related by a single entry are derived by chaining entries via
+/// intermediate units. Thus, for N units, N-1 entries are sufficient to
+/// define all conversions.
 #[derive(Debug)]
 pub struct ConversionTable<Q: Quantity, const N: usize> {
     /// Table of tuples (from_unit, to_unit, factor, offset), defining the
@@ -34,14 +106,199 @@ pub struct ConversionTable<Q: Quantity, const N: usize> {
     pub mappings: [(Q::UnitType, Q::UnitType, AmountT, AmountT); N],
 }
 
+impl<Q: Quantity, const N: usize> ConversionTable<Q, N> {
+    /// Returns `(factor, offset)` so that
+    /// to_amount = from_amount * factor + offset
+    /// defines the conversion from `from_unit` to `to_unit`, or `None` if
+    /// there is no such conversion.
+    pub fn conversion(
+        &self,
+        from_unit: Q::UnitType,
+        to_unit: Q::UnitType,
+    ) -> Option<(AmountT, AmountT)> {
+        find_conversion(&self.mappings, from_unit, to_unit)
+    }
+}
+
 impl<Q: Quantity, const N: usize> Converter<Q> for ConversionTable<Q, N> {
-    fn convert(self, qty: &Q, to_unit: Q::UnitType) -> Option<Q> {
-        if (*qty).unit() == to_unit {
+    fn convert(&self, qty: &Q, to_unit: Q::UnitType) -> Option<Q> {
+        if qty.unit() == to_unit {
             return Some(*qty);
         }
-        self.mappings.iter().find_map(|(from, to, factor, offset)| {
-            (*from == (*qty).unit() && *to == to_unit)
-                .then(|| Q::new(qty.amount() * factor + offset, to_unit))
+        self.conversion(qty.unit(), to_unit).map(|(factor, offset)| {
+            Q::new(qty.amount() * factor + offset, to_unit)
         })
     }
 }
+
+/// Trait for converters between instances of different quantities which are
+/// equivalent under some physical relation, like mass and energy (E = m·c²).
+pub trait Equivalence<A: Quantity, B: Quantity> {
+    /// Returns `conv` where `conv` ≣ `qty` and `conv.unit()` is `to_unit`, or
+    /// `None` if conversion is not possible.
+    fn convert(&self, qty: &A, to_unit: B::UnitType) -> Option<B>;
+
+    /// Returns `conv` where `conv` ≣ `qty` and `conv.unit()` is `to_unit`, or
+    /// `None` if conversion is not possible.
+    fn convert_back(&self, qty: &B, to_unit: A::UnitType) -> Option<A>;
+}
+
+impl<A: Quantity, B: Quantity, E: Equivalence<A, B>> Equivalence<A, B> for &E {
+    #[inline(always)]
+    fn convert(&self, qty: &A, to_unit: B::UnitType) -> Option<B> {
+        (*self).convert(qty, to_unit)
+    }
+
+    #[inline(always)]
+    fn convert_back(&self, qty: &B, to_unit: A::UnitType) -> Option<A> {
+        (*self).convert_back(qty, to_unit)
+    }
+}
+
+#[inline(always)]
+fn from_ref_amount<Q>(amnt: AmountT, to_unit: Q::UnitType) -> Q
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+{
+    Q::new(amnt / to_unit.scale(), to_unit)
+}
+
+/// Equivalence of quantities `A` and `B` defined by
+/// b = a * factor
+/// where a and b are the amounts in the reference units of `A` and `B`.
+#[derive(Clone, Copy, Debug)]
+pub struct ProportionalEquivalence<A, B> {
+    factor: AmountT,
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A, B> ProportionalEquivalence<A, B> {
+    /// Returns a new `ProportionalEquivalence` with the given `factor`.
+    #[must_use]
+    pub const fn new(factor: AmountT) -> Self {
+        Self {
+            factor,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Equivalence<A, B> for ProportionalEquivalence<A, B>
+where
+    A: HasRefUnit,
+    A::UnitType: LinearScaledUnit,
+    B: HasRefUnit,
+    B::UnitType: LinearScaledUnit,
+{
+    fn convert(&self, qty: &A, to_unit: B::UnitType) -> Option<B> {
+        let amnt = qty.equiv_amount(A::REF_UNIT) * self.factor;
+        Some(from_ref_amount(amnt, to_unit))
+    }
+
+    fn convert_back(&self, qty: &B, to_unit: A::UnitType) -> Option<A> {
+        if self.factor == AMNT_ZERO {
+            return None;
+        }
+        let amnt = qty.equiv_amount(B::REF_UNIT) / self.factor;
+        Some(from_ref_amount(amnt, to_unit))
+    }
+}
+
+/// Equivalence of quantities `A` and `B` defined by
+/// a * b = constant
+/// where a and b are the amounts in the reference units of `A` and `B`.
+///
+/// Conversion of a zero amount is not possible.
+#[derive(Clone, Copy, Debug)]
+pub struct ReciprocalEquivalence<A, B> {
+    constant: AmountT,
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A, B> ReciprocalEquivalence<A, B> {
+    /// Returns a new `ReciprocalEquivalence` with the given `constant`.
+    #[must_use]
+    pub const fn new(constant: AmountT) -> Self {
+        Self {
+            constant,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Equivalence<A, B> for ReciprocalEquivalence<A, B>
+where
+    A: HasRefUnit,
+    A::UnitType: LinearScaledUnit,
+    B: HasRefUnit,
+    B::UnitType: LinearScaledUnit,
+{
+    fn convert(&self, qty: &A, to_unit: B::UnitType) -> Option<B> {
+        let amnt = qty.equiv_amount(A::REF_UNIT);
+        if amnt == AMNT_ZERO {
+            return None;
+        }
+        Some(from_ref_amount(self.constant / amnt, to_unit))
+    }
+
+    fn convert_back(&self, qty: &B, to_unit: A::UnitType) -> Option<A> {
+        let amnt = qty.equiv_amount(B::REF_UNIT);
+        if amnt == AMNT_ZERO {
+            return None;
+        }
+        Some(from_ref_amount(self.constant / amnt, to_unit))
+    }
+}
+
+/// Equivalence of quantities `A` and `B` depending on a context value of
+/// type `C`, defined by a pair of functions mapping `A` to `B` and vice
+/// versa.
+///
+/// The results of the functions are converted to the requested unit.
+#[derive(Clone, Copy, Debug)]
+pub struct ContextEquivalence<A, B, C> {
+    context: C,
+    forward: fn(&C, &A) -> Option<B>,
+    backward: fn(&C, &B) -> Option<A>,
+}
+
+impl<A, B, C> ContextEquivalence<A, B, C> {
+    /// Returns a new `ContextEquivalence` with the given `context` and the
+    /// functions `forward` (mapping `A` to `B`) and `backward` (mapping `B`
+    /// to `A`).
+    #[must_use]
+    pub const fn new(
+        context: C,
+        forward: fn(&C, &A) -> Option<B>,
+        backward: fn(&C, &B) -> Option<A>,
+    ) -> Self {
+        Self {
+            context,
+            forward,
+            backward,
+        }
+    }
+
+    /// Returns a reference to the context of `self`.
+    #[must_use]
+    pub const fn context(&self) -> &C {
+        &self.context
+    }
+}
+
+impl<A, B, C> Equivalence<A, B> for ContextEquivalence<A, B, C>
+where
+    A: HasRefUnit,
+    A::UnitType: LinearScaledUnit,
+    B: HasRefUnit,
+    B::UnitType: LinearScaledUnit,
+{
+    fn convert(&self, qty: &A, to_unit: B::UnitType) -> Option<B> {
+        (self.forward)(&self.context, qty).map(|res| res.convert(to_unit))
+    }
+
+    fn convert_back(&self, qty: &B, to_unit: A::UnitType) -> Option<A> {
+        (self.backward)(&self.context, qty).map(|res| res.convert(to_unit))
+    }
+}
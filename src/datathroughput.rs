@@ -9,7 +9,10 @@
 
 //! Definition of derived quantity `DataThroughput`.
 
-use crate::{datavolume::DataVolume, duration::Duration, prelude::*};
+use crate::{
+    datavolume::DataVolume, duration::Duration, prelude::*,
+    ContextEquivalence, AMNT_ZERO,
+};
 
 #[quantity(DataVolume / Duration)]
 #[ref_unit(
@@ -64,11 +67,33 @@ use crate::{datavolume::DataVolume, duration::Duration, prelude::*};
 /// | TiB/s  | Tebibyte per Second   | 1099511627776·B/s | 1099511627776       |
 pub struct DataThroughput {}
 
+/// Returns the equivalence of data volume and duration of its transmission
+/// at the given throughput.
+#[must_use]
+pub const fn transmission_at(
+    throughput: DataThroughput,
+) -> ContextEquivalence<DataVolume, Duration, DataThroughput> {
+    ContextEquivalence::new(
+        throughput,
+        |thr, vol| {
+            if thr.amount() == AMNT_ZERO {
+                None
+            } else {
+                Some(*vol / *thr)
+            }
+        },
+        |thr, dur| Some(*dur * *thr),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        assert_almost_eq, datavolume::MEBIBYTE, duration::MILLISECOND,
+        assert_almost_eq,
+        datavolume::{GIGABYTE, MEBIBYTE},
+        duration::{MILLISECOND, MINUTE, SECOND},
+        Equivalence,
     };
 
     #[test]
@@ -81,4 +106,19 @@ mod tests {
         assert_almost_eq!(r.amount(), ad / at * Amnt!(1.048576));
         assert_eq!(r.unit(), GIGABYTE_PER_SECOND);
     }
+
+    #[test]
+    fn test_transmission_equivalence() {
+        let eqv = transmission_at(Amnt!(100.) * MEGABYTE_PER_SECOND);
+        let d = Amnt!(1.) * GIGABYTE;
+        let t = eqv.convert(&d, SECOND).unwrap();
+        assert_almost_eq!(t.amount(), Amnt!(10.));
+        assert_eq!(t.unit(), SECOND);
+        let t = Amnt!(1.) * MINUTE;
+        let d = eqv.convert_back(&t, GIGABYTE).unwrap();
+        assert_almost_eq!(d.amount(), Amnt!(6.));
+        assert_eq!(d.unit(), GIGABYTE);
+        let eqv = transmission_at(Amnt!(0) * MEGABYTE_PER_SECOND);
+        assert!(eqv.convert(&d, SECOND).is_none());
+    }
 }
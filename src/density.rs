@@ -0,0 +1,89 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Density`.
+
+use crate::{mass::Mass, prelude::*, volume::Volume};
+
+#[quantity(Mass / Volume)]
+#[ref_unit(
+    Kilogram_per_Cubic_Meter,
+    "kg/m³",
+    NONE,
+    "Reference unit of quantity `Density`"
+)]
+#[unit(Milligram_per_Liter, "mg/l", 0.001, "mg/l")]
+#[unit(Gram_per_Liter, "g/l", 1, "g/l")]
+#[unit(Pound_per_Cubic_Foot, "lb/ft³", 16.01846337396014, "lb/ft³")]
+#[unit(Kilogram_per_Liter, "kg/l", 1000, "kg/l")]
+#[unit(Gram_per_Cubic_Centimeter, "g/cm³", 1000, "g/cm³")]
+#[unit(Pound_per_Cubic_Inch, "lb/in³", 27679.904710203125, "lb/in³")]
+/// Mass per unit of volume
+///
+/// Definition: Mass/Volume
+///
+/// Reference unit: Kilogram per Cubic Meter ('kg/m³')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                      | Definition | Equiv. in 'kg/m³'  |
+/// |--------|---------------------------|------------|--------------------|
+/// | mg/l   | Milligram per Liter       | mg/l       | 0.001              |
+/// | g/l    | Gram per Liter            | g/l        | 1                  |
+/// | lb/ft³ | Pound per Cubic Foot      | lb/ft³     | 16.01846337396014  |
+/// | kg/l   | Kilogram per Liter        | kg/l       | 1000               |
+/// | g/cm³  | Gram per Cubic Centimeter | g/cm³      | 1000               |
+/// | lb/in³ | Pound per Cubic Inch      | lb/in³     | 27679.904710203125 |
+pub struct Density {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        mass::{GRAM, KILOGRAM, POUND},
+        volume::{CUBIC_CENTIMETER, CUBIC_FOOT, LITER},
+    };
+
+    #[test]
+    fn test_density() {
+        assert_eq!(<Density as HasRefUnit>::REF_UNIT, DensityUnit::REF_UNIT);
+        assert!(KILOGRAM_PER_CUBIC_METER.is_ref_unit());
+        let d = Amnt!(1.) * GRAM_PER_CUBIC_CENTIMETER;
+        let e = d.convert(POUND_PER_CUBIC_FOOT);
+        assert_almost_eq!(e.amount(), Amnt!(62.427960576144606));
+    }
+
+    #[test]
+    fn test_mass_div_volume() {
+        let am: AmountT = Amnt!(19.3);
+        let m = am * GRAM;
+        let av: AmountT = Amnt!(1.);
+        let v = av * CUBIC_CENTIMETER;
+        let d = m / v;
+        assert_almost_eq!(d.equiv_amount(GRAM_PER_CUBIC_CENTIMETER), am / av);
+        let m = Amnt!(62.4) * POUND;
+        let v = Amnt!(1.) * CUBIC_FOOT;
+        let d = m / v;
+        assert_almost_eq!(d.equiv_amount(POUND_PER_CUBIC_FOOT), Amnt!(62.4));
+    }
+
+    #[test]
+    fn test_density_mul_volume() {
+        let ad: AmountT = Amnt!(0.789);
+        let d = ad * KILOGRAM_PER_LITER;
+        let av: AmountT = Amnt!(2.5);
+        let v = av * LITER;
+        let m = d * v;
+        assert_almost_eq!(m.amount(), ad * av);
+        assert_eq!(m.unit(), KILOGRAM);
+        let v = m / d;
+        assert_almost_eq!(v.equiv_amount(LITER), av);
+    }
+}
@@ -9,7 +9,9 @@
 
 //! Definition of basic quantity `Duration`.
 
-use crate::prelude::*;
+use alloc::format;
+
+use crate::{amnt_checked_add, amnt_checked_mul, prelude::*, AMNT_ZERO};
 
 #[quantity]
 #[ref_unit(Second, "s", NONE, "Reference unit of quantity `Duration`")]
@@ -19,6 +21,10 @@ use crate::prelude::*;
 #[unit(Minute, "min", 60, "60·s")]
 #[unit(Hour, "h", 3600, "60·min")]
 #[unit(Day, "d", 86400, "24·h")]
+#[unit(Week, "wk", 604800, "7·d")]
+#[unit(Gregorian_Month, "mo", 2629746, "yr/12")]
+#[unit(Gregorian_Year, "yr", 31556952, "365.2425·d")]
+#[unit(Julian_Year, "a", 31557600, "365.25·d")]
 /// Duration: 'what a clock reads'
 ///
 /// Reference unit: Second ('s')
@@ -33,8 +39,186 @@ use crate::prelude::*;
 /// | min    | Minute                | 60·s              | 60                  |
 /// | h      | Hour                  | 60·min            | 3600                |
 /// | d      | Day                   | 24·h              | 86400               |
+/// | wk     | Week                  | 7·d               | 604800              |
+/// | mo     | Gregorian Month       | yr/12             | 2629746             |
+/// | yr     | Gregorian Year        | 365.2425·d        | 31556952            |
+/// | a      | Julian Year           | 365.25·d          | 31557600            |
 pub struct Duration {}
 
+/// Error returned when a string can't be parsed as ISO 8601 duration.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseIsoDurationError;
+
+impl fmt::Display for ParseIsoDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid ISO 8601 duration")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseIsoDurationError {}
+
+const DATE_DESIGNATORS: [(char, DurationUnit); 4] = [
+    ('Y', GREGORIAN_YEAR),
+    ('M', GREGORIAN_MONTH),
+    ('W', WEEK),
+    ('D', DAY),
+];
+const TIME_DESIGNATORS: [(char, DurationUnit); 3] =
+    [('H', HOUR), ('M', MINUTE), ('S', SECOND)];
+
+// Adds the components in `s` to `total` and returns the number of
+// components found. The components must be given in the order of
+// `designators`, each at most once. Only the last component may have a
+// decimal fraction; `has_fraction` tells whether one has been found.
+fn parse_iso_components(
+    s: &str,
+    designators: &[(char, DurationUnit)],
+    total: &mut AmountT,
+    has_fraction: &mut bool,
+) -> Result<usize, ParseIsoDurationError> {
+    let mut designators = designators.iter();
+    let mut n_components = 0;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let Some(idx) = rest.find(|c: char| c.is_ascii_alphabetic()) else {
+            return Err(ParseIsoDurationError);
+        };
+        if *has_fraction {
+            return Err(ParseIsoDurationError);
+        }
+        let (number, tail) = rest.split_at(idx);
+        *has_fraction = number.contains(['.', ',']);
+        let mut chars = tail.chars();
+        let designator = chars.next().ok_or(ParseIsoDurationError)?;
+        let (_, unit) = designators
+            .find(|(d, _)| *d == designator)
+            .ok_or(ParseIsoDurationError)?;
+        *total = amnt_checked_mul(parse_iso_number(number)?, unit.scale())
+            .and_then(|secs| amnt_checked_add(*total, secs))
+            .ok_or(ParseIsoDurationError)?;
+        n_components += 1;
+        rest = chars.as_str();
+    }
+    Ok(n_components)
+}
+
+fn parse_iso_number(s: &str) -> Result<AmountT, ParseIsoDurationError> {
+    let (int_part, frac_part) = match s.split_once(['.', ',']) {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (s, None),
+    };
+    let is_digits =
+        |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(int_part) || !frac_part.map_or(true, is_digits) {
+        return Err(ParseIsoDurationError);
+    }
+    let number = match frac_part {
+        Some(frac_part) => format!("{int_part}.{frac_part}"),
+        None => int_part.to_owned(),
+    };
+    number.parse().map_err(|_| ParseIsoDurationError)
+}
+
+#[allow(clippy::multiple_inherent_impl)]
+impl Duration {
+    /// Returns the duration represented by the ISO 8601 string `s`, given
+    /// in seconds.
+    ///
+    /// `s` must have the form `PnYnMnWnDTnHnMnS`, where each component is
+    /// optional, but at least one must be given. The number of the last
+    /// component given may have a decimal fraction, separated by `.` or `,`.
+    /// A leading `-` denotes a negative duration.
+    ///
+    /// As years and months are nominal durations in ISO 8601, they are
+    /// interpreted as mean Gregorian years and months.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseIsoDurationError` if `s` is not a valid ISO 8601
+    /// duration or if the duration can't be represented as `AmountT`.
+    pub fn from_iso8601(s: &str) -> Result<Self, ParseIsoDurationError> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let s = s.strip_prefix('P').ok_or(ParseIsoDurationError)?;
+        let (date, time) = match s.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (s, None),
+        };
+        let mut total = AMNT_ZERO;
+        let mut has_fraction = false;
+        let n_date = parse_iso_components(
+            date,
+            &DATE_DESIGNATORS,
+            &mut total,
+            &mut has_fraction,
+        )?;
+        let n_time = match time {
+            Some(time) => {
+                match parse_iso_components(
+                    time,
+                    &TIME_DESIGNATORS,
+                    &mut total,
+                    &mut has_fraction,
+                )? {
+                    0 => return Err(ParseIsoDurationError),
+                    n => n,
+                }
+            }
+            None => 0,
+        };
+        if n_date + n_time == 0 {
+            return Err(ParseIsoDurationError);
+        }
+        if negative {
+            total = -total;
+        }
+        Ok(Self::new(total, SECOND))
+    }
+
+    /// Returns an ISO 8601 representation of `self`.
+    ///
+    /// The duration is split into days, hours, minutes and seconds. The
+    /// designators for years, months and weeks are never emitted, because
+    /// the number of days in them is not fixed. So, a duration parsed from
+    /// "P1Y" is represented as "P365DT5H49M12S".
+    #[must_use]
+    pub fn to_iso8601(&self) -> String {
+        let secs = self.equiv_amount(SECOND);
+        let sign = if secs < AMNT_ZERO { "-" } else { "" };
+        let mut rest = if secs < AMNT_ZERO { -secs } else { secs };
+        let [days, hours, minutes] = [DAY, HOUR, MINUTE].map(|unit| {
+            let scale = unit.scale();
+            let remainder = rest % scale;
+            let n = (rest - remainder) / scale;
+            rest = remainder;
+            n
+        });
+        let seconds = rest;
+        let mut res = format!("{sign}P");
+        if days != AMNT_ZERO {
+            res += &format!("{days}D");
+        }
+        if hours != AMNT_ZERO || minutes != AMNT_ZERO || seconds != AMNT_ZERO {
+            res.push('T');
+            if hours != AMNT_ZERO {
+                res += &format!("{hours}H");
+            }
+            if minutes != AMNT_ZERO {
+                res += &format!("{minutes}M");
+            }
+            if seconds != AMNT_ZERO {
+                res += &format!("{seconds}S");
+            }
+        } else if days == AMNT_ZERO {
+            res += "T0S";
+        }
+        res
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +232,107 @@ mod tests {
         #[cfg(feature = "std")]
         assert_eq!(d.to_string(), "29.35 ms");
     }
+
+    #[test]
+    fn test_calendar_units() {
+        let d = Amnt!(2.) * WEEK;
+        assert_eq!(d.convert(DAY).amount(), Amnt!(14.));
+        let d = Amnt!(1.) * GREGORIAN_YEAR;
+        assert_eq!(d.convert(GREGORIAN_MONTH).amount(), Amnt!(12.));
+        assert_eq!(d.convert(DAY).amount(), Amnt!(365.2425));
+        let d = Amnt!(4.) * JULIAN_YEAR;
+        assert_eq!(d.convert(DAY).amount(), Amnt!(1461.));
+    }
+
+    #[test]
+    fn test_from_iso8601() {
+        let d = Duration::from_iso8601("PT1H30M").unwrap();
+        assert_eq!(d.amount(), Amnt!(5400.));
+        assert_eq!(d.unit(), SECOND);
+        let d = Duration::from_iso8601("P3DT4H").unwrap();
+        assert_eq!(d, Amnt!(76.) * HOUR);
+        let d = Duration::from_iso8601("P1Y2M").unwrap();
+        assert_eq!(d, Amnt!(14.) * GREGORIAN_MONTH);
+        let d = Duration::from_iso8601("P2W").unwrap();
+        assert_eq!(d, Amnt!(2.) * WEEK);
+        let d = Duration::from_iso8601("PT0,5S").unwrap();
+        assert_eq!(d, Amnt!(500.) * MILLISECOND);
+        let d = Duration::from_iso8601("-PT2.25M").unwrap();
+        assert_eq!(d.amount(), Amnt!(-135.));
+        let d = Duration::from_iso8601("PT36H").unwrap();
+        assert_eq!(d, Amnt!(1.5) * DAY);
+        let d = Duration::from_iso8601("P1DT0,5H").unwrap();
+        assert_eq!(d, Amnt!(1470.) * MINUTE);
+    }
+
+    #[test]
+    fn test_from_iso8601_invalid() {
+        for s in [
+            "",
+            "P",
+            "PT",
+            "P1DT",
+            "1D",
+            "PT1D",
+            "P1H",
+            "PT1M1H",
+            "P1D1D",
+            "PT1.S",
+            "PT.5S",
+            "PT1.2.3S",
+            "PTS",
+            "P-1D",
+            "PT+1S",
+            "PT1e3S",
+            "P1DX",
+            "PT1H ",
+            "PT1.5H30M",
+            "P0.5DT1H",
+            "P1,5Y2M",
+        ] {
+            assert_eq!(
+                Duration::from_iso8601(s),
+                Err(ParseIsoDurationError),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_iso8601_overflow() {
+        let s = format!("P{}Y", "9".repeat(400));
+        assert_eq!(Duration::from_iso8601(&s), Err(ParseIsoDurationError));
+        // Overflow of the internal representation of `Decimal`, either when
+        // multiplying a component by the scale of its unit or when adding up
+        // the components.
+        #[cfg(feature = "fpdec")]
+        for s in [
+            format!("P{}Y", "9".repeat(34)),
+            format!("P3{}Y4{}M", "0".repeat(30), "0".repeat(31)),
+        ] {
+            assert_eq!(
+                Duration::from_iso8601(&s),
+                Err(ParseIsoDurationError),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_iso8601() {
+        let d = Amnt!(5400.) * SECOND;
+        assert_eq!(d.to_iso8601(), "PT1H30M");
+        let d = Amnt!(76.) * HOUR;
+        assert_eq!(d.to_iso8601(), "P3DT4H");
+        let d = Amnt!(2.) * WEEK;
+        assert_eq!(d.to_iso8601(), "P14D");
+        let d = Amnt!(-90.5) * SECOND;
+        assert_eq!(d.to_iso8601(), "-PT1M30.5S");
+        let d = Amnt!(0) * HOUR;
+        assert_eq!(d.to_iso8601(), "PT0S");
+        let d = Duration::from_iso8601("P1DT2H3M4.5S").unwrap();
+        assert_eq!(d.to_iso8601(), "P1DT2H3M4.5S");
+        let d = Duration::from_iso8601("P1Y").unwrap();
+        assert_eq!(d.to_iso8601(), "P365DT5H49M12S");
+    }
 }
@@ -0,0 +1,55 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of basic quantity `ElectricCurrent`.
+
+use crate::prelude::*;
+
+#[quantity]
+#[ref_unit(Ampere, "A", NONE, "Reference unit of quantity `ElectricCurrent`")]
+#[unit(Nanoampere, "nA", NANO, 0.000000001, "0.000000001·A")]
+#[unit(Microampere, "µA", MICRO, 0.000001, "0.000001·A")]
+#[unit(Milliampere, "mA", MILLI, 0.001, "0.001·A")]
+#[unit(Kiloampere, "kA", KILO, 1000, "1000·A")]
+/// Flow of electric charge
+///
+/// Reference unit: Ampere ('A')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'A'   |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | nA     | Nanoampere            | 0.000000001·A     | 0.000000001         |
+/// | µA     | Microampere           | 0.000001·A        | 0.000001            |
+/// | mA     | Milliampere           | 0.001·A           | 0.001               |
+/// | kA     | Kiloampere            | 1000·A            | 1000                |
+pub struct ElectricCurrent {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_almost_eq;
+
+    #[test]
+    fn test_electric_current() {
+        assert_eq!(
+            <ElectricCurrent as HasRefUnit>::REF_UNIT,
+            ElectricCurrentUnit::REF_UNIT
+        );
+        assert!(AMPERE.is_ref_unit());
+        let amnt: AmountT = Amnt!(29.35);
+        let i = amnt * MILLIAMPERE;
+        assert_eq!(i.amount, amnt);
+        assert_eq!(i.unit, MILLIAMPERE);
+        #[cfg(feature = "std")]
+        assert_eq!(i.to_string(), "29.35 mA");
+        let i = i.convert(MICROAMPERE);
+        assert_almost_eq!(i.amount, amnt * Amnt!(1000.));
+    }
+}
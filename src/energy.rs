@@ -9,12 +9,18 @@
 
 //! Definition of derived quantity `Energy`.
 
-use crate::{force::Force, length::Length, prelude::*};
+use crate::{
+    force::Force, length::Length, mass::Mass, prelude::*,
+    ProportionalEquivalence,
+};
 
 #[quantity(Force * Length)]
 #[ref_unit(Joule, "J", NONE, "Reference unit of quantity `Energy`")]
-#[unit(Newton_Meter, "Nm", NONE, 1, "N·m")]
 #[unit(Watt_Second, "Ws", NONE, 1, "W·s")]
+#[unit(Foot_Pound_Force, "ft·lbf", 1.3558179483314004, "ft·lbf")]
+#[unit(Thermochemical_Calorie, "cal_th", 4.184, "4.184·J")]
+#[unit(IT_British_Thermal_Unit, "Btu_IT", 1055.05585262, "1055.05585262·J")]
+#[unit(Thermochemical_Kilocalorie, "kcal_th", 4184, "1000·cal_th")]
 #[unit(Kilowatt_Hour, "kWh", 3600000, "kW·h")]
 /// Property that must be transferred to an object in order to perform work on
 /// or to heat it.
@@ -25,17 +31,27 @@ use crate::{force::Force, length::Length, prelude::*};
 ///
 /// Predefined units:
 ///
-/// | Symbol | Name                    | Definition        | Equivalent in 'J' |
-/// |--------|-------------------------|-------------------|-------------------|
-/// | Nm     | Newton Meter            | N·m               | 1                 |
-/// | Ws     | Watt Second             | W·s               | 1                 |
-/// | kWh    | Kilowatt Hour           | kW·h              | 3600000           |
+/// | Symbol  | Name                    | Definition      | Equivalent in 'J'  |
+/// |---------|-------------------------|-----------------|--------------------|
+/// | Ws      | Watt Second             | W·s             | 1                  |
+/// | ft·lbf  | Foot-pound-force        | ft·lbf          | 1.3558179483314004 |
+/// | cal_th  | Thermochem. Calorie     | 4.184·J         | 4.184              |
+/// | Btu_IT  | IT British Thermal Unit | 1055.05585262·J | 1055.05585262      |
+/// | kcal_th | Thermochem. Kilocalorie | 1000·cal_th     | 4184               |
+/// | kWh     | Kilowatt Hour           | kW·h            | 3600000            |
 pub struct Energy {}
 
+/// Equivalence of mass and energy: E = m·c²
+pub const MASS_ENERGY: ProportionalEquivalence<Mass, Energy> =
+    ProportionalEquivalence::new(Amnt!(89875517873681764.));
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{assert_almost_eq, force::NEWTON, length::KILOMETER};
+    use crate::{
+        assert_almost_eq, force::NEWTON, length::KILOMETER, mass::GRAM,
+        Equivalence,
+    };
 
     #[test]
     fn test_force_mul_length() {
@@ -47,4 +63,28 @@ mod tests {
         assert_almost_eq!(e.amount(), af * al * Amnt!(1000.));
         assert_eq!(e.unit(), JOULE);
     }
+
+    #[test]
+    fn test_mass_energy_equivalence() {
+        let m = Amnt!(1.) * GRAM;
+        let e = MASS_ENERGY.convert(&m, KILOWATT_HOUR).unwrap();
+        assert_almost_eq!(e.amount(), Amnt!(24965421.63157827));
+        assert_eq!(e.unit(), KILOWATT_HOUR);
+        let m = MASS_ENERGY.convert_back(&e, GRAM).unwrap();
+        assert_almost_eq!(m.amount(), Amnt!(1.));
+        assert_eq!(m.unit(), GRAM);
+    }
+
+    #[test]
+    fn test_customary_units() {
+        let e = Amnt!(1.) * KILOWATT_HOUR;
+        let f = e.convert(IT_BRITISH_THERMAL_UNIT);
+        assert_almost_eq!(f.amount(), Amnt!(3412.141633127942));
+        let e = Amnt!(2.5) * THERMOCHEMICAL_KILOCALORIE;
+        let f = e.convert(THERMOCHEMICAL_CALORIE);
+        assert_almost_eq!(f.amount(), Amnt!(2500.));
+        let e = Amnt!(1.) * FOOT_POUND_FORCE;
+        let f = e.convert(JOULE);
+        assert_almost_eq!(f.amount(), Amnt!(1.3558179483314004));
+    }
 }
@@ -13,7 +13,11 @@ use crate::{acceleration::Acceleration, mass::Mass, prelude::*};
 
 #[quantity(Mass * Acceleration)]
 #[ref_unit(Newton, "N", NONE, "Reference unit of quantity `Force`")]
+#[unit(Poundal, "pdl", 0.138254954376, "lb·ft/s²")]
 #[unit(Joule_per_Meter, "J/m", NONE, 1, "J/m")]
+#[unit(Pound_Force, "lbf", 4.4482216152605, "lb·gₙ")]
+#[unit(Kilogram_Force, "kgf", 9.80665, "kg·gₙ")]
+#[unit(Kip, "kip", 4448.2216152605, "1000·lbf")]
 /// Influence that can accelerate an object with mass.
 ///
 /// Definition: Mass·Acceleration = Mass·Length/Duration²
@@ -22,9 +26,13 @@ use crate::{acceleration::Acceleration, mass::Mass, prelude::*};
 ///
 /// Predefined units:
 ///
-/// | Symbol | Name                    | Definition      | Equivalent in 'N'   |
-/// |--------|-------------------------|-----------------|---------------------|
-/// | J/m    | Joule per Meter         | J/m             | 1                   |
+/// | Symbol | Name                  | Definition        | Equivalent in 'N'   |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | pdl    | Poundal               | lb·ft/s²          | 0.138254954376      |
+/// | J/m    | Joule per Meter       | J/m               | 1                   |
+/// | lbf    | Pound-force           | lb·gₙ             | 4.4482216152605     |
+/// | kgf    | Kilogram-force        | kg·gₙ             | 9.80665             |
+/// | kip    | Kip                   | 1000·lbf          | 4448.2216152605     |
 pub struct Force {}
 
 #[cfg(test)]
@@ -44,4 +52,17 @@ mod tests {
         assert_almost_eq!(f.amount(), aa * am / Amnt!(1000.));
         assert_eq!(f.unit(), NEWTON);
     }
+
+    #[test]
+    fn test_customary_units() {
+        let f = Amnt!(2.5) * KIP;
+        let g = f.convert(POUND_FORCE);
+        assert_almost_eq!(g.amount(), Amnt!(2500.));
+        let f = Amnt!(1.) * POUND_FORCE;
+        let g = f.convert(NEWTON);
+        assert_almost_eq!(g.amount(), Amnt!(4.4482216152605));
+        let f = Amnt!(1.) * KILOGRAM_FORCE;
+        let g = f.convert(POUNDAL);
+        assert_almost_eq!(g.amount(), Amnt!(70.93163528396751));
+    }
 }
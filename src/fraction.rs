@@ -0,0 +1,107 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of dimensionless quantity `Fraction`.
+
+use crate::prelude::*;
+
+#[quantity]
+#[ref_unit(Unity, "", "Reference unit of quantity `Fraction`")]
+#[unit(Parts_per_Billion, "ppb", 0.000000001, "0.000000001")]
+#[unit(Parts_per_Million, "ppm", 0.000001, "0.000001")]
+#[unit(Per_Mille, "‰", 0.001, "0.001")]
+#[unit(Percent, "%", 0.01, "0.01")]
+/// Dimensionless ratio of two values of the same quantity
+///
+/// Values of `Fraction` can be converted from and into `AmountT`, so that
+/// they can be used where the "unitless" quantity is expected. Its reference
+/// unit is named `UNITY` to distinguish it from `ONE`, the unit of `AmountT`.
+///
+/// Reference unit: Unity ('')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in ''    |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | ppb    | Parts per Billion     | 0.000000001       | 0.000000001         |
+/// | ppm    | Parts per Million     | 0.000001          | 0.000001            |
+/// | ‰      | Per Mille             | 0.001             | 0.001               |
+/// | %      | Percent               | 0.01              | 0.01                |
+pub struct Fraction {}
+
+impl From<AmountT> for Fraction {
+    #[inline(always)]
+    fn from(amount: AmountT) -> Self {
+        Self::new(amount, UNITY)
+    }
+}
+
+impl From<Fraction> for AmountT {
+    #[inline(always)]
+    fn from(fraction: Fraction) -> Self {
+        fraction.equiv_amount(UNITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_almost_eq;
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(<Fraction as HasRefUnit>::REF_UNIT, FractionUnit::REF_UNIT);
+        assert!(UNITY.is_ref_unit());
+        let amnt: AmountT = Amnt!(2.5);
+        let f = amnt * PERCENT;
+        assert_eq!(f.amount, amnt);
+        assert_eq!(f.unit, PERCENT);
+        #[cfg(feature = "std")]
+        assert_eq!(f.to_string(), "2.5 %");
+        let g = f.convert(PER_MILLE);
+        assert_almost_eq!(g.amount, Amnt!(25.));
+        let g = f.convert(PARTS_PER_MILLION);
+        assert_almost_eq!(g.amount, Amnt!(25000.));
+        let g = Amnt!(300.) * PARTS_PER_BILLION;
+        assert_almost_eq!(g.equiv_amount(PARTS_PER_MILLION), Amnt!(0.3));
+        assert!(g < f);
+    }
+
+    #[test]
+    fn test_amount_conversion() {
+        let f = Fraction::from(Amnt!(0.25));
+        assert_eq!(f.amount, Amnt!(0.25));
+        assert_eq!(f.unit, UNITY);
+        #[cfg(feature = "std")]
+        assert_eq!(f.to_string(), "0.25");
+        assert_almost_eq!(f.equiv_amount(PERCENT), Amnt!(25.));
+        let a: AmountT = (Amnt!(7.5) * PER_MILLE).into();
+        assert_almost_eq!(a, Amnt!(0.0075));
+        // `ONE` from the prelude is not shadowed by `UNITY`
+        assert_eq!(a.unit(), ONE);
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn test_rate() {
+        use crate::duration::{Duration, JULIAN_YEAR};
+
+        let rate = Rate::<Fraction, Duration>::new(
+            Amnt!(3),
+            PERCENT,
+            Amnt!(1),
+            JULIAN_YEAR,
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(rate.to_string(), "3 % / a");
+        let f = rate * (Amnt!(2.5) * JULIAN_YEAR);
+        assert_eq!(f.unit, PERCENT);
+        assert_almost_eq!(f.amount, Amnt!(7.5));
+    }
+}
@@ -10,6 +10,8 @@
 //! Definition of derived quantity `Frequency`.
 
 use crate::{duration::Duration, prelude::*};
+#[cfg(feature = "length")]
+use crate::{length::Length, ReciprocalEquivalence};
 
 #[quantity(AmountT / Duration)]
 #[ref_unit(Hertz, "Hz", NONE, "Reference unit of quantity `Frequency`")]
@@ -31,10 +33,18 @@ use crate::{duration::Duration, prelude::*};
 /// | GHz    | Gigahertz             | 1000000000·Hz     | 1000000000          |
 pub struct Frequency {}
 
+/// Equivalence of wavelength and frequency of electromagnetic waves in
+/// vacuum: λ = c / f
+#[cfg(feature = "length")]
+pub const WAVELENGTH_FREQUENCY: ReciprocalEquivalence<Length, Frequency> =
+    ReciprocalEquivalence::new(Amnt!(299792458.));
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{assert_almost_eq, duration::MILLISECOND};
+    #[cfg(feature = "length")]
+    use crate::{length::NANOMETER, Equivalence};
 
     #[test]
     fn test_amount_div_duration() {
@@ -45,4 +55,18 @@ mod tests {
         assert_almost_eq!(f.amount(), a / at);
         assert_eq!(f.unit(), KILOHERTZ);
     }
+
+    #[cfg(feature = "length")]
+    #[test]
+    fn test_wavelength_frequency_equivalence() {
+        let l = Amnt!(500.) * NANOMETER;
+        let f = WAVELENGTH_FREQUENCY.convert(&l, GIGAHERTZ).unwrap();
+        assert_almost_eq!(f.amount(), Amnt!(599584.916));
+        assert_eq!(f.unit(), GIGAHERTZ);
+        let l = WAVELENGTH_FREQUENCY.convert_back(&f, NANOMETER).unwrap();
+        assert_almost_eq!(l.amount(), Amnt!(500.));
+        assert_eq!(l.unit(), NANOMETER);
+        let f = Amnt!(0) * HERTZ;
+        assert!(WAVELENGTH_FREQUENCY.convert_back(&f, NANOMETER).is_none());
+    }
 }
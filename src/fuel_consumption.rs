@@ -0,0 +1,94 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `FuelConsumption`.
+
+use crate::{area::Area, length::Length, prelude::*, volume::Volume};
+
+#[quantity(Volume / Length, distinct_from = Area)]
+#[ref_unit(
+    Cubic_Meter_per_Meter,
+    "m³/m",
+    NONE,
+    "Reference unit of quantity `FuelConsumption`"
+)]
+#[unit(Liter_per_100_Kilometers, "l/100km", 0.00000001, "l/100km")]
+#[unit(Liter_per_Kilometer, "l/km", 0.000001, "l/km")]
+/// Volume of fuel consumed per distance travelled
+///
+/// Definition: Volume/Length
+///
+/// Fuel consumption has the same dimension as `Area`, but is a distinct
+/// quantity. Therefore, `Volume / Length` results in `Area`; use
+/// `FuelConsumption::from_quotient` to get the fuel consumption from a volume
+/// of fuel and a distance.
+///
+/// Fuel consumption is the reciprocal of
+/// [FuelEconomy](crate::fuel_economy::FuelEconomy), which provides units like
+/// miles per gallon.
+///
+/// Reference unit: Cubic Meter per Meter ('m³/m')
+///
+/// Predefined units:
+///
+/// | Symbol  | Name                     | Definition | Equivalent in 'm³/m' |
+/// |---------|--------------------------|------------|----------------------|
+/// | l/100km | Liter per 100 Kilometers | l/100km    | 0.00000001           |
+/// | l/km    | Liter per Kilometer      | l/km       | 0.000001             |
+pub struct FuelConsumption {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        length::{KILOMETER, METER},
+        volume::{CUBIC_METER, LITER},
+    };
+
+    #[test]
+    fn test_fuel_consumption() {
+        assert_eq!(
+            <FuelConsumption as HasRefUnit>::REF_UNIT,
+            FuelConsumptionUnit::REF_UNIT
+        );
+        assert!(CUBIC_METER_PER_METER.is_ref_unit());
+        let c = Amnt!(6.5) * LITER_PER_100_KILOMETERS;
+        let d = c.convert(LITER_PER_KILOMETER);
+        assert_almost_eq!(d.amount(), Amnt!(0.065));
+    }
+
+    #[test]
+    fn test_fuel_consumption_from_quotient() {
+        let v = Amnt!(39.) * LITER;
+        let l = Amnt!(600.) * KILOMETER;
+        let c = FuelConsumption::from_quotient(v, l);
+        assert_almost_eq!(
+            c.equiv_amount(LITER_PER_100_KILOMETERS),
+            Amnt!(6.5)
+        );
+        let v = Amnt!(2.) * CUBIC_METER;
+        let l = Amnt!(4.) * METER;
+        let c = FuelConsumption::from_quotient(v, l);
+        assert_eq!(c.amount(), Amnt!(0.5));
+        assert_eq!(c.unit(), CUBIC_METER_PER_METER);
+    }
+
+    #[test]
+    fn test_fuel_consumption_mul_length() {
+        let c = Amnt!(7.2) * LITER_PER_100_KILOMETERS;
+        let l = Amnt!(250.) * KILOMETER;
+        let v = c * l;
+        assert_almost_eq!(v.equiv_amount(LITER), Amnt!(18.));
+        let v = l * c;
+        assert_almost_eq!(v.equiv_amount(LITER), Amnt!(18.));
+        let l = v / c;
+        assert_almost_eq!(l.equiv_amount(KILOMETER), Amnt!(250.));
+    }
+}
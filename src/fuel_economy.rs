@@ -0,0 +1,211 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `FuelEconomy`.
+
+use crate::{
+    fuel_consumption::{FuelConsumption, CUBIC_METER_PER_METER},
+    length::Length,
+    prelude::*,
+    volume::Volume,
+    ReciprocalEquivalence, AMNT_ONE, AMNT_ZERO,
+};
+
+#[quantity(Length / Volume)]
+#[ref_unit(
+    Meter_per_Cubic_Meter,
+    "m/m³",
+    NONE,
+    "Reference unit of quantity `FuelEconomy`"
+)]
+#[unit(Miles_per_Imperial_Gallon, "mpg imp", 354006.1899346471, "mi/imp gal")]
+#[unit(Miles_per_US_Gallon, "mpg US", 425143.707430272, "mi/US gal")]
+#[unit(Kilometer_per_Liter, "km/l", 1000000, "km/l")]
+/// Distance travelled per volume of fuel consumed
+///
+/// Definition: Length/Volume
+///
+/// Fuel economy is the reciprocal of
+/// [FuelConsumption](crate::fuel_consumption::FuelConsumption). Use
+/// [FUEL_CONSUMPTION_ECONOMY] to convert between both.
+///
+/// Reference unit: Meter per Cubic Meter ('m/m³')
+///
+/// Predefined units:
+///
+/// | Symbol  | Name                      | Definition | Equivalent in 'm/m³' |
+/// |---------|---------------------------|------------|----------------------|
+/// | mpg imp | Miles per Imperial Gallon | mi/imp gal | 354006.1899346471    |
+/// | mpg US  | Miles per US Gallon       | mi/US gal  | 425143.707430272     |
+/// | km/l    | Kilometer per Liter       | km/l       | 1000000              |
+pub struct FuelEconomy {}
+
+/// Equivalence of fuel consumption and fuel economy: c = 1 / e
+pub const FUEL_CONSUMPTION_ECONOMY: ReciprocalEquivalence<
+    FuelConsumption,
+    FuelEconomy,
+> = ReciprocalEquivalence::new(AMNT_ONE);
+
+// Compares `econ` to the fuel economy represented by `cons`. Negative
+// values have no reciprocal counterpart, so they are not comparable.
+fn cmp_economy(
+    econ: &FuelEconomy,
+    cons: &FuelConsumption,
+) -> Option<Ordering> {
+    let e = econ.equiv_amount(METER_PER_CUBIC_METER);
+    let c = cons.equiv_amount(CUBIC_METER_PER_METER);
+    if c < AMNT_ZERO {
+        None
+    } else if e > AMNT_ZERO {
+        PartialOrd::partial_cmp(&(c * e), &AMNT_ONE)
+    } else if e == AMNT_ZERO {
+        // zero economy is less than any finite consumption, including zero
+        // consumption, which represents an infinite economy
+        Some(Ordering::Less)
+    } else {
+        None
+    }
+}
+
+/// Fuel economy and fuel consumption are compared by the distance travelled
+/// per volume of fuel, i.e. the lower fuel consumption is the greater one.
+impl PartialEq<FuelConsumption> for FuelEconomy {
+    #[inline]
+    fn eq(&self, other: &FuelConsumption) -> bool {
+        cmp_economy(self, other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<FuelConsumption> for FuelEconomy {
+    #[inline]
+    fn partial_cmp(&self, other: &FuelConsumption) -> Option<Ordering> {
+        cmp_economy(self, other)
+    }
+}
+
+impl PartialEq<FuelEconomy> for FuelConsumption {
+    #[inline]
+    fn eq(&self, other: &FuelEconomy) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<FuelEconomy> for FuelConsumption {
+    #[inline]
+    fn partial_cmp(&self, other: &FuelEconomy) -> Option<Ordering> {
+        cmp_economy(other, self).map(Ordering::reverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq, fuel_consumption::LITER_PER_100_KILOMETERS,
+        length::KILOMETER, volume::LITER, Equivalence,
+    };
+
+    #[test]
+    fn test_fuel_economy() {
+        assert_eq!(
+            <FuelEconomy as HasRefUnit>::REF_UNIT,
+            FuelEconomyUnit::REF_UNIT
+        );
+        assert!(METER_PER_CUBIC_METER.is_ref_unit());
+        let e = Amnt!(30.) * MILES_PER_US_GALLON;
+        let f = e.convert(MILES_PER_IMPERIAL_GALLON);
+        assert_almost_eq!(f.amount(), Amnt!(36.02849776514565));
+    }
+
+    #[test]
+    fn test_length_div_volume() {
+        let l = Amnt!(600.) * KILOMETER;
+        let v = Amnt!(40.) * LITER;
+        let e = l / v;
+        assert_almost_eq!(e.equiv_amount(KILOMETER_PER_LITER), Amnt!(15.));
+        // In f32, the scale of km/l (10⁶) is not met exactly.
+        #[cfg(not(feature = "f32"))]
+        assert_eq!(e.unit(), KILOMETER_PER_LITER);
+        let v = e * v;
+        assert_almost_eq!(v.equiv_amount(KILOMETER), Amnt!(600.));
+    }
+
+    #[test]
+    fn test_fuel_consumption_economy_equivalence() {
+        let c = Amnt!(6.5) * LITER_PER_100_KILOMETERS;
+        let e = FUEL_CONSUMPTION_ECONOMY
+            .convert(&c, MILES_PER_US_GALLON)
+            .unwrap();
+        assert_almost_eq!(e.amount(), Amnt!(36.18685897435898));
+        let e = FUEL_CONSUMPTION_ECONOMY
+            .convert(&c, MILES_PER_IMPERIAL_GALLON)
+            .unwrap();
+        assert_almost_eq!(e.amount(), Amnt!(43.4586055895111));
+        let e = Amnt!(20.) * KILOMETER_PER_LITER;
+        let c = FUEL_CONSUMPTION_ECONOMY
+            .convert_back(&e, LITER_PER_100_KILOMETERS)
+            .unwrap();
+        assert_almost_eq!(c.amount(), Amnt!(5.));
+    }
+
+    #[test]
+    fn test_cmp_fuel_consumption_economy() {
+        let c = Amnt!(6.5) * LITER_PER_100_KILOMETERS;
+        let e = Amnt!(30.) * MILES_PER_US_GALLON;
+        assert!(c > e);
+        assert!(e < c);
+        let e = Amnt!(40.) * MILES_PER_US_GALLON;
+        assert!(c < e);
+        assert!(e > c);
+        let c = Amnt!(0.5) * CUBIC_METER_PER_METER;
+        let e = Amnt!(2.) * METER_PER_CUBIC_METER;
+        assert!(c == e);
+        assert!(e == c);
+        let e = Amnt!(0) * METER_PER_CUBIC_METER;
+        assert!(c > e);
+        assert!(e < c);
+    }
+
+    #[test]
+    fn test_cmp_zero_and_negative() {
+        let c = Amnt!(0) * CUBIC_METER_PER_METER;
+        let e = Amnt!(2.) * METER_PER_CUBIC_METER;
+        assert!(e < c);
+        assert!(c > e);
+        let e = Amnt!(0) * METER_PER_CUBIC_METER;
+        assert!(e < c);
+        assert!(c > e);
+        let c = Amnt!(-0.5) * CUBIC_METER_PER_METER;
+        assert!(PartialOrd::partial_cmp(&e, &c).is_none());
+        let e = Amnt!(2.) * METER_PER_CUBIC_METER;
+        assert!(PartialOrd::partial_cmp(&e, &c).is_none());
+        assert!(PartialOrd::partial_cmp(&c, &e).is_none());
+        assert!(e != c);
+        let c = Amnt!(0.5) * CUBIC_METER_PER_METER;
+        let e = Amnt!(-2.) * METER_PER_CUBIC_METER;
+        assert!(PartialOrd::partial_cmp(&e, &c).is_none());
+        assert!(PartialOrd::partial_cmp(&c, &e).is_none());
+        let c = Amnt!(0) * CUBIC_METER_PER_METER;
+        assert!(PartialOrd::partial_cmp(&e, &c).is_none());
+    }
+
+    #[test]
+    fn test_cmp_transitivity() {
+        // 6.5 l/100km ≈ 36.19 mpg US
+        let e1 = Amnt!(30.) * MILES_PER_US_GALLON;
+        let c = Amnt!(6.5) * LITER_PER_100_KILOMETERS;
+        let e2 = Amnt!(40.) * MILES_PER_US_GALLON;
+        assert!(e1 < c);
+        assert!(c < e2);
+        assert!(e1 < e2);
+        assert!(e2 > c);
+        assert!(c > e1);
+        assert!(e2 > e1);
+    }
+}
@@ -0,0 +1,86 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Illuminance`.
+
+use crate::{area::Area, luminous_flux::LuminousFlux, prelude::*};
+
+#[quantity(LuminousFlux / Area)]
+#[ref_unit(Lux, "lx", NONE, "Reference unit of quantity `Illuminance`")]
+#[unit(Foot_Candle, "fc", 10.763910416709722, "lm/ft²")]
+#[unit(Kilolux, "klx", KILO, 1000, "1000·lx")]
+#[unit(Phot, "ph", 10000, "lm/cm²")]
+/// Luminous flux incident on a surface per unit area
+///
+/// Definition: LuminousFlux/Area
+///
+/// Reference unit: Lux ('lx' = 'lm/m²')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'lx'  |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | fc     | Foot-candle           | lm/ft²            | 10.763910416709722  |
+/// | klx    | Kilolux               | 1000·lx           | 1000                |
+/// | ph     | Phot                  | lm/cm²            | 10000               |
+pub struct Illuminance {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        area::{SQUARE_FOOT, SQUARE_METER},
+        assert_almost_eq,
+        luminous_flux::LUMEN,
+    };
+
+    #[test]
+    fn test_illuminance() {
+        assert_eq!(
+            <Illuminance as HasRefUnit>::REF_UNIT,
+            IlluminanceUnit::REF_UNIT
+        );
+        assert!(LUX.is_ref_unit());
+        let e = Amnt!(50.) * FOOT_CANDLE;
+        let f = e.convert(LUX);
+        assert_almost_eq!(f.amount(), Amnt!(538.1955208354861));
+        let e = Amnt!(2.5) * PHOT;
+        let f = e.convert(KILOLUX);
+        assert_almost_eq!(f.amount(), Amnt!(25.));
+    }
+
+    #[test]
+    fn test_luminous_flux_div_area() {
+        let af: AmountT = Amnt!(800.);
+        let f = af * LUMEN;
+        let aa: AmountT = Amnt!(4.);
+        let a = aa * SQUARE_METER;
+        let e = f / a;
+        assert_almost_eq!(e.amount(), af / aa);
+        assert_eq!(e.unit(), LUX);
+        let a = aa * SQUARE_FOOT;
+        let e = f / a;
+        assert_almost_eq!(e.equiv_amount(FOOT_CANDLE), af / aa);
+    }
+
+    #[test]
+    fn test_illuminance_mul_area() {
+        let ae: AmountT = Amnt!(300.);
+        let e = ae * LUX;
+        let aa: AmountT = Amnt!(12.5);
+        let a = aa * SQUARE_METER;
+        let f = e * a;
+        assert_almost_eq!(f.amount(), ae * aa);
+        assert_eq!(f.unit(), LUMEN);
+        let f = a * e;
+        assert_almost_eq!(f.amount(), ae * aa);
+        let a = f / e;
+        assert_almost_eq!(a.equiv_amount(SQUARE_METER), aa);
+    }
+}
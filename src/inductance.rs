@@ -0,0 +1,65 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Inductance`.
+
+use crate::{duration::Duration, prelude::*, resistance::Resistance};
+
+#[quantity(Resistance * Duration)]
+#[ref_unit(Henry, "H", NONE, "Reference unit of quantity `Inductance`")]
+#[unit(Nanohenry, "nH", NANO, 0.000000001, "0.000000001·H")]
+#[unit(Microhenry, "µH", MICRO, 0.000001, "0.000001·H")]
+#[unit(Millihenry, "mH", MILLI, 0.001, "0.001·H")]
+/// Tendency of an electrical conductor to oppose a change in the electric
+/// current flowing through it
+///
+/// Definition: Resistance·Duration
+///
+/// Reference unit: Henry ('H' = 'Ω·s' = 'kg·m²/(s²·A²)')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'H'   |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | nH     | Nanohenry             | 0.000000001·H     | 0.000000001         |
+/// | µH     | Microhenry            | 0.000001·H        | 0.000001            |
+/// | mH     | Millihenry            | 0.001·H           | 0.001               |
+pub struct Inductance {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        duration::{MICROSECOND, MILLISECOND},
+        resistance::{KILOOHM, OHM},
+    };
+
+    #[test]
+    fn test_resistance_mul_duration() {
+        let ar: AmountT = Amnt!(1.5);
+        let r = ar * KILOOHM;
+        let at: AmountT = Amnt!(20.);
+        let t = at * MICROSECOND;
+        let l = r * t;
+        assert_almost_eq!(l.amount(), ar * at);
+        assert_eq!(l.unit(), MILLIHENRY);
+    }
+
+    #[test]
+    fn test_inductance_div_resistance() {
+        let al: AmountT = Amnt!(33.);
+        let l = al * MILLIHENRY;
+        let ar: AmountT = Amnt!(11.);
+        let r = ar * OHM;
+        let t = l / r;
+        assert_almost_eq!(t.amount(), al / ar);
+        assert_eq!(t.unit(), MILLISECOND);
+    }
+}
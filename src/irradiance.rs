@@ -0,0 +1,95 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Irradiance`.
+
+use crate::{area::Area, power::Power, prelude::*};
+
+#[quantity(Power / Area)]
+#[ref_unit(
+    Watt_per_Square_Meter,
+    "W/m²",
+    NONE,
+    "Reference unit of quantity `Irradiance`"
+)]
+#[unit(Milliwatt_per_Square_Centimeter, "mW/cm²", 10, "mW/cm²")]
+#[unit(Kilowatt_per_Square_Meter, "kW/m²", KILO, 1000, "kW/m²")]
+#[unit(Watt_per_Square_Centimeter, "W/cm²", 10000, "W/cm²")]
+/// Radiant power received by a surface per unit area
+///
+/// Definition: Power/Area
+///
+/// Reference unit: Watt per Square Meter ('W/m²' = 'kg/s³')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                            | Definition | Equiv. in 'W/m²' |
+/// |--------|---------------------------------|------------|------------------|
+/// | mW/cm² | Milliwatt per Square Centimeter | mW/cm²     | 10               |
+/// | kW/m²  | Kilowatt per Square Meter       | kW/m²      | 1000             |
+/// | W/cm²  | Watt per Square Centimeter      | W/cm²      | 10000            |
+pub struct Irradiance {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        area::{SQUARE_CENTIMETER, SQUARE_METER},
+        assert_almost_eq,
+        power::{KILOWATT, MILLIWATT, WATT},
+    };
+
+    #[test]
+    fn test_irradiance() {
+        assert_eq!(
+            <Irradiance as HasRefUnit>::REF_UNIT,
+            IrradianceUnit::REF_UNIT
+        );
+        assert!(WATT_PER_SQUARE_METER.is_ref_unit());
+        let e = Amnt!(1.361) * KILOWATT_PER_SQUARE_METER;
+        let f = e.convert(MILLIWATT_PER_SQUARE_CENTIMETER);
+        assert_almost_eq!(f.amount(), Amnt!(136.1));
+    }
+
+    #[test]
+    fn test_power_div_area() {
+        let ap: AmountT = Amnt!(150.);
+        let p = ap * MILLIWATT;
+        let aa: AmountT = Amnt!(3.);
+        let a = aa * SQUARE_CENTIMETER;
+        let e = p / a;
+        assert_almost_eq!(
+            e.equiv_amount(MILLIWATT_PER_SQUARE_CENTIMETER),
+            ap / aa
+        );
+        // In f32, the scale of mW/cm² (10) is not met exactly.
+        #[cfg(not(feature = "f32"))]
+        assert_eq!(e.unit(), MILLIWATT_PER_SQUARE_CENTIMETER);
+        let p = ap * KILOWATT;
+        let a = aa * SQUARE_METER;
+        let e = p / a;
+        assert_almost_eq!(e.amount(), ap / aa);
+        assert_eq!(e.unit(), KILOWATT_PER_SQUARE_METER);
+    }
+
+    #[test]
+    fn test_irradiance_mul_area() {
+        let ae: AmountT = Amnt!(800.);
+        let e = ae * WATT_PER_SQUARE_METER;
+        let aa: AmountT = Amnt!(1.75);
+        let a = aa * SQUARE_METER;
+        let p = e * a;
+        assert_almost_eq!(p.amount(), ae * aa);
+        assert_eq!(p.unit(), WATT);
+        let p = a * e;
+        assert_almost_eq!(p.amount(), ae * aa);
+        let a = p / e;
+        assert_almost_eq!(a.equiv_amount(SQUARE_METER), aa);
+    }
+}
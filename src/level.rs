@@ -0,0 +1,463 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of logarithmic quantity `Ratio` and of levels, i.e. ratios
+//! relative to a reference value of a quantity.
+//!
+//! Converting between logarithmic and linear values needs the floating
+//! point functions of the standard library, so it is only available if
+//! feature `std` is enabled.
+
+use core::ops::Div;
+
+#[cfg(feature = "power")]
+use crate::power::{Power, MILLIWATT, WATT};
+#[cfg(feature = "pressure")]
+use crate::pressure::{Pressure, PASCAL};
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::{amnt_from_f64, amnt_to_f64};
+
+#[quantity]
+#[ref_unit(Decibel, "dB", NONE, "Reference unit of quantity `Ratio`")]
+#[unit(Neper, "Np", 8.685889638065035, "20/ln(10)·dB")]
+#[unit(Bel, "B", 10, "10·dB")]
+/// Logarithmic ratio of two values of a power quantity (like `Power`) or a
+/// root-power quantity (like `Pressure` or `Voltage`)
+///
+/// As ratios are logarithmic, adding two ratios results in the product of
+/// the underlying linear ratios.
+///
+/// Reference unit: Decibel ('dB')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'dB'  |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | Np     | Neper                 | 20/ln(10)·dB      | 8.685889638065035   |
+/// | B      | Bel                   | 10·dB             | 10                  |
+pub struct Ratio {}
+
+#[cfg(feature = "std")]
+const POWER_FACTOR: f64 = 10.;
+#[cfg(feature = "std")]
+const ROOT_POWER_FACTOR: f64 = 20.;
+
+// Returns `factor`·log₁₀(`ratio`) as `AmountT`.
+#[cfg(feature = "std")]
+fn log_ratio(ratio: AmountT, factor: f64) -> AmountT {
+    let res = factor * amnt_to_f64(ratio).log10();
+    match amnt_from_f64(res) {
+        Some(amnt) => amnt,
+        None => panic!("Ratio must be a positive number: {ratio}"),
+    }
+}
+
+// Returns 10^(`amount` / `factor`) as `AmountT`.
+#[cfg(feature = "std")]
+fn lin_ratio(amount: AmountT, factor: f64) -> AmountT {
+    let res = 10_f64.powf(amnt_to_f64(amount) / factor);
+    match amnt_from_f64(res) {
+        Some(amnt) => amnt,
+        None => panic!("Result not representable as AmountT: {res}"),
+    }
+}
+
+// `AmountT` is already marked as `must_use` if feature `fpdec` is enabled.
+#[cfg(feature = "std")]
+#[allow(clippy::multiple_inherent_impl, clippy::must_use_candidate)]
+impl Ratio {
+    /// Returns the ratio corresponding to the linear ratio `ratio` of two
+    /// values of a power quantity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not a positive number.
+    pub fn from_power_ratio(ratio: AmountT) -> Self {
+        Self::new(log_ratio(ratio, POWER_FACTOR), DECIBEL)
+    }
+
+    /// Returns the ratio corresponding to the linear ratio `ratio` of two
+    /// values of a root-power quantity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not a positive number.
+    pub fn from_root_power_ratio(ratio: AmountT) -> Self {
+        Self::new(log_ratio(ratio, ROOT_POWER_FACTOR), DECIBEL)
+    }
+
+    /// Returns the linear ratio of two values of a power quantity
+    /// corresponding to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can not be represented as `AmountT`.
+    pub fn power_ratio(&self) -> AmountT {
+        lin_ratio(self.equiv_amount(DECIBEL), POWER_FACTOR)
+    }
+
+    /// Returns the linear ratio of two values of a root-power quantity
+    /// corresponding to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can not be represented as `AmountT`.
+    pub fn root_power_ratio(&self) -> AmountT {
+        lin_ratio(self.equiv_amount(DECIBEL), ROOT_POWER_FACTOR)
+    }
+}
+
+/// Unit of a level, i.e. of a ratio in decibel relative to a reference
+/// value of a quantity.
+pub trait LevelUnit: Copy + Eq + Sized + fmt::Debug {
+    /// Quantity the level refers to.
+    type QuantityType: Quantity
+        + Div<Output = AmountT>
+        + Mul<AmountT, Output = Self::QuantityType>;
+
+    /// `true` if `QuantityType` is a root-power quantity, `false` if it is a
+    /// power quantity.
+    const ROOT_POWER: bool;
+
+    /// Returns the name of `self`.
+    fn name(&self) -> String;
+
+    /// Returns the symbol used to represent `self`.
+    fn symbol(&self) -> String;
+
+    /// Returns the reference value of `self`.
+    fn reference(&self) -> Self::QuantityType;
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+const fn log_factor<U: LevelUnit>() -> f64 {
+    if U::ROOT_POWER {
+        ROOT_POWER_FACTOR
+    } else {
+        POWER_FACTOR
+    }
+}
+
+/// Ratio of a value of a quantity to the reference value of a level unit,
+/// given in decibel.
+///
+/// Adding a `Ratio` to a level gives a level, subtracting two levels gives a
+/// `Ratio`. Comparing and subtracting levels need feature `std`, as levels
+/// with different units have to be converted.
+#[derive(Copy, Clone, Debug)]
+pub struct Level<U: LevelUnit> {
+    amount: AmountT,
+    unit: U,
+}
+
+impl<U: LevelUnit> Level<U> {
+    /// Returns a new level with the given `amount` and `unit`.
+    #[inline(always)]
+    pub const fn new(amount: AmountT, unit: U) -> Self {
+        Self { amount, unit }
+    }
+
+    /// Returns the amount of `self`.
+    #[inline(always)]
+    pub const fn amount(&self) -> AmountT {
+        self.amount
+    }
+
+    /// Returns the unit of `self`.
+    #[inline(always)]
+    pub const fn unit(&self) -> U {
+        self.unit
+    }
+
+    /// Returns the level of `qty` relative to the reference value of `unit`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `qty` is not positive.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_qty(qty: U::QuantityType, unit: U) -> Self {
+        Self::new(log_ratio(qty / unit.reference(), log_factor::<U>()), unit)
+    }
+
+    /// Returns the value of the quantity represented by `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result can not be represented as `AmountT`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_qty(&self) -> U::QuantityType {
+        self.unit.reference() * lin_ratio(self.amount, log_factor::<U>())
+    }
+
+    /// Returns the level equivalent to `self`, relative to the reference
+    /// value of `to_unit`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn convert(&self, to_unit: U) -> Self {
+        if self.unit == to_unit {
+            return *self;
+        }
+        let offset = log_ratio(
+            self.unit.reference() / to_unit.reference(),
+            log_factor::<U>(),
+        );
+        Self::new(self.amount + offset, to_unit)
+    }
+}
+
+impl<U: LevelUnit> fmt::Display for Level<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.amount, f)?;
+        write!(f, " {}", self.unit.symbol())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U: LevelUnit> PartialEq for Level<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.convert(self.unit).amount
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U: LevelUnit> PartialOrd for Level<U> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&self.amount, &other.convert(self.unit).amount)
+    }
+}
+
+impl<U: LevelUnit> Add<Ratio> for Level<U> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Ratio) -> Self::Output {
+        Self::new(self.amount + rhs.equiv_amount(DECIBEL), self.unit)
+    }
+}
+
+impl<U: LevelUnit> Sub<Ratio> for Level<U> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Ratio) -> Self::Output {
+        Self::new(self.amount - rhs.equiv_amount(DECIBEL), self.unit)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U: LevelUnit> Sub<Self> for Level<U> {
+    type Output = Ratio;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Ratio::new(self.amount - rhs.convert(self.unit).amount, DECIBEL)
+    }
+}
+
+/// Unit of quantity `PowerLevel`.
+#[cfg(feature = "power")]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PowerLevelUnit {
+    /// Decibel relative to 1 mW
+    Decibel_Milliwatt,
+    /// Decibel relative to 1 W
+    Decibel_Watt,
+}
+
+#[cfg(feature = "power")]
+impl LevelUnit for PowerLevelUnit {
+    type QuantityType = Power;
+
+    const ROOT_POWER: bool = false;
+
+    fn name(&self) -> String {
+        match self {
+            Self::Decibel_Milliwatt => "Decibel Milliwatt",
+            Self::Decibel_Watt => "Decibel Watt",
+        }
+        .to_owned()
+    }
+
+    fn symbol(&self) -> String {
+        match self {
+            Self::Decibel_Milliwatt => "dBm",
+            Self::Decibel_Watt => "dBW",
+        }
+        .to_owned()
+    }
+
+    fn reference(&self) -> Power {
+        match self {
+            Self::Decibel_Milliwatt => Power::new(Amnt!(1), MILLIWATT),
+            Self::Decibel_Watt => Power::new(Amnt!(1), WATT),
+        }
+    }
+}
+
+/// Decibel relative to 1 mW
+#[cfg(feature = "power")]
+pub const DECIBEL_MILLIWATT: PowerLevelUnit =
+    PowerLevelUnit::Decibel_Milliwatt;
+/// Decibel relative to 1 W
+#[cfg(feature = "power")]
+pub const DECIBEL_WATT: PowerLevelUnit = PowerLevelUnit::Decibel_Watt;
+
+/// Level of a power relative to a reference power
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Reference         |
+/// |--------|-----------------------|-------------------|
+/// | dBm    | Decibel Milliwatt     | 1 mW              |
+/// | dBW    | Decibel Watt          | 1 W               |
+#[cfg(feature = "power")]
+pub type PowerLevel = Level<PowerLevelUnit>;
+
+/// Unit of quantity `SoundPressureLevel`.
+#[cfg(feature = "pressure")]
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SoundPressureLevelUnit {
+    /// Decibel relative to 20 µPa
+    Decibel_SPL,
+}
+
+#[cfg(feature = "pressure")]
+impl LevelUnit for SoundPressureLevelUnit {
+    type QuantityType = Pressure;
+
+    const ROOT_POWER: bool = true;
+
+    fn name(&self) -> String {
+        "Decibel Sound Pressure Level".to_owned()
+    }
+
+    fn symbol(&self) -> String {
+        "dB(SPL)".to_owned()
+    }
+
+    fn reference(&self) -> Pressure {
+        Pressure::new(Amnt!(0.00002), PASCAL)
+    }
+}
+
+/// Decibel relative to 20 µPa
+#[cfg(feature = "pressure")]
+pub const DECIBEL_SPL: SoundPressureLevelUnit =
+    SoundPressureLevelUnit::Decibel_SPL;
+
+/// Level of a sound pressure relative to the threshold of human hearing
+///
+/// Predefined units:
+///
+/// | Symbol  | Name                         | Reference        |
+/// |---------|------------------------------|------------------|
+/// | dB(SPL) | Decibel Sound Pressure Level | 20 µPa           |
+#[cfg(feature = "pressure")]
+pub type SoundPressureLevel = Level<SoundPressureLevelUnit>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_almost_eq;
+
+    #[test]
+    fn test_ratio() {
+        assert_eq!(<Ratio as HasRefUnit>::REF_UNIT, RatioUnit::REF_UNIT);
+        assert!(DECIBEL.is_ref_unit());
+        let r = Amnt!(1.) * NEPER;
+        assert_almost_eq!(r.equiv_amount(DECIBEL), Amnt!(8.685889638065035));
+        let r = Amnt!(0.3) * BEL;
+        assert_almost_eq!(r.equiv_amount(DECIBEL), Amnt!(3.));
+        let r = Amnt!(3) * DECIBEL + Amnt!(3) * DECIBEL;
+        assert_almost_eq!(r.amount(), Amnt!(6));
+        #[cfg(feature = "std")]
+        assert_almost_eq!(r.power_ratio(), Amnt!(3.9810717055349722));
+        #[cfg(feature = "std")]
+        assert_eq!(r.to_string(), "6 dB");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_linear_ratios() {
+        let r = Ratio::from_power_ratio(Amnt!(2.));
+        assert_almost_eq!(r.amount(), Amnt!(3.010299956639812));
+        assert_eq!(r.unit(), DECIBEL);
+        let r = Ratio::from_root_power_ratio(Amnt!(10.));
+        assert_almost_eq!(r.amount(), Amnt!(20.));
+        let r = Amnt!(20.) * DECIBEL;
+        assert_almost_eq!(r.power_ratio(), Amnt!(100.));
+        assert_almost_eq!(r.root_power_ratio(), Amnt!(10.));
+        // adding ratios multiplies the linear ratios
+        let s = Ratio::from_power_ratio(Amnt!(4.));
+        assert_almost_eq!((r + s).power_ratio(), Amnt!(400.));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn test_non_positive_ratio() {
+        let _ = Ratio::from_power_ratio(Amnt!(0));
+    }
+
+    #[cfg(all(feature = "power", feature = "std"))]
+    #[test]
+    fn test_power_level() {
+        let p = Amnt!(100.) * MILLIWATT;
+        let l = PowerLevel::from_qty(p, DECIBEL_MILLIWATT);
+        assert_almost_eq!(l.amount(), Amnt!(20.));
+        assert_eq!(l.unit(), DECIBEL_MILLIWATT);
+        assert_eq!(l.to_string(), "20 dBm");
+        let m = l.convert(DECIBEL_WATT);
+        assert_almost_eq!(m.amount(), Amnt!(-10.));
+        assert_eq!(m.unit(), DECIBEL_WATT);
+        let l = PowerLevel::new(Amnt!(30.), DECIBEL_MILLIWATT);
+        assert_almost_eq!(l.to_qty().equiv_amount(WATT), Amnt!(1.));
+        assert!(l == PowerLevel::new(Amnt!(0), DECIBEL_WATT));
+        assert!(l > PowerLevel::new(Amnt!(-1.), DECIBEL_WATT));
+    }
+
+    #[cfg(all(feature = "power", feature = "std"))]
+    #[test]
+    fn test_power_level_arithmetic() {
+        let l = PowerLevel::new(Amnt!(20.), DECIBEL_MILLIWATT);
+        let gain = Amnt!(3.) * DECIBEL;
+        let m = l + gain;
+        assert_almost_eq!(m.amount(), Amnt!(23.));
+        assert_eq!(m.unit(), DECIBEL_MILLIWATT);
+        let m = m - Amnt!(1.) * BEL;
+        assert_almost_eq!(m.amount(), Amnt!(13.));
+        let r = l - PowerLevel::new(Amnt!(-20.), DECIBEL_WATT);
+        assert_almost_eq!(r.amount(), Amnt!(10.));
+        assert_eq!(r.unit(), DECIBEL);
+        assert_almost_eq!(r.power_ratio(), Amnt!(10.));
+    }
+
+    #[cfg(all(feature = "pressure", feature = "std"))]
+    #[test]
+    fn test_sound_pressure_level() {
+        let p = Amnt!(1.) * PASCAL;
+        let l = SoundPressureLevel::from_qty(p, DECIBEL_SPL);
+        assert_almost_eq!(l.amount(), Amnt!(93.97940008672037));
+        let l = SoundPressureLevel::new(Amnt!(94.), DECIBEL_SPL);
+        assert_almost_eq!(
+            l.to_qty().equiv_amount(PASCAL),
+            Amnt!(1.0023744672545452)
+        );
+        // doubling the sound pressure adds about 6 dB
+        let l = SoundPressureLevel::from_qty(p, DECIBEL_SPL);
+        let m = SoundPressureLevel::from_qty(Amnt!(2.) * PASCAL, DECIBEL_SPL);
+        assert_almost_eq!((m - l).amount(), Amnt!(6.020599913279624));
+    }
+}
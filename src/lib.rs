@@ -65,6 +65,12 @@ use core::{
 
 #[cfg(feature = "fpdec")]
 pub use amnt_dec::{AMNT_ONE, AMNT_ZERO, AmountT, Dec, Decimal};
+#[cfg(feature = "fpdec")]
+#[allow(unused_imports)]
+pub(crate) use amnt_dec::{
+    amnt_checked_add, amnt_checked_mul, amnt_from_f64, amnt_from_usize,
+    amnt_round, amnt_to_f64,
+};
 #[cfg(all(
     not(feature = "fpdec"),
     any(
@@ -78,6 +84,23 @@ pub use amnt_dec::{AMNT_ONE, AMNT_ZERO, AmountT, Dec, Decimal};
     )
 ))]
 pub use amnt_f32::{AMNT_ONE, AMNT_ZERO, AmountT};
+#[cfg(all(
+    not(feature = "fpdec"),
+    any(
+        all(feature = "f32", not(feature = "f64")),
+        all(feature = "f32", feature = "f64", target_pointer_width = "32"),
+        all(
+            not(feature = "f32"),
+            not(feature = "f64"),
+            target_pointer_width = "32"
+        )
+    )
+))]
+#[allow(unused_imports)]
+pub(crate) use amnt_f32::{
+    amnt_checked_add, amnt_checked_mul, amnt_from_f64, amnt_from_usize,
+    amnt_round, amnt_to_f64,
+};
 #[cfg(all(
     not(feature = "fpdec"),
     any(
@@ -91,14 +114,41 @@ pub use amnt_f32::{AMNT_ONE, AMNT_ZERO, AmountT};
     )
 ))]
 pub use amnt_f64::{AMNT_ONE, AMNT_ZERO, AmountT};
-pub use converter::{ConversionTable, Converter};
+#[cfg(all(
+    not(feature = "fpdec"),
+    any(
+        all(not(feature = "f32"), feature = "f64"),
+        all(feature = "f32", feature = "f64", target_pointer_width = "64"),
+        all(
+            not(feature = "f32"),
+            not(feature = "f64"),
+            target_pointer_width = "64"
+        )
+    )
+))]
+#[allow(unused_imports)]
+pub(crate) use amnt_f64::{
+    amnt_checked_add, amnt_checked_mul, amnt_from_f64, amnt_from_usize,
+    amnt_round, amnt_to_f64,
+};
+pub use converter::{
+    ContextEquivalence, ConversionTable, Converter, Equivalence,
+    ProportionalEquivalence, ReciprocalEquivalence,
+};
 pub use rate::Rate;
 pub use si_prefixes::SIPrefix;
 
+pub mod constants;
 mod converter;
 pub mod prelude;
+#[cfg(feature = "ndarray")]
+pub mod qty_array;
+pub mod qty_vec;
+pub mod range;
 mod rate;
 mod si_prefixes;
+pub mod statistics;
+pub mod vector;
 
 #[cfg(feature = "fpdec")]
 #[doc(hidden)]
@@ -134,32 +184,84 @@ pub mod amnt_f64;
 
 #[cfg(feature = "acceleration")]
 pub mod acceleration;
+#[cfg(feature = "amount_of_substance")]
+pub mod amount_of_substance;
+#[cfg(feature = "angle")]
+pub mod angle;
+#[cfg(feature = "angular_velocity")]
+pub mod angular_velocity;
 #[cfg(feature = "area")]
 pub mod area;
+#[cfg(feature = "capacitance")]
+pub mod capacitance;
 #[cfg(feature = "datathroughput")]
 pub mod datathroughput;
 #[cfg(feature = "datavolume")]
 pub mod datavolume;
+#[cfg(feature = "density")]
+pub mod density;
 #[cfg(feature = "duration")]
 pub mod duration;
+#[cfg(feature = "electric_charge")]
+pub mod electric_charge;
+#[cfg(feature = "electric_current")]
+pub mod electric_current;
 #[cfg(feature = "energy")]
 pub mod energy;
 #[cfg(feature = "force")]
 pub mod force;
+#[cfg(feature = "fraction")]
+pub mod fraction;
 #[cfg(feature = "frequency")]
 pub mod frequency;
+#[cfg(feature = "fuel_consumption")]
+pub mod fuel_consumption;
+#[cfg(feature = "fuel_economy")]
+pub mod fuel_economy;
+#[cfg(feature = "illuminance")]
+pub mod illuminance;
+#[cfg(feature = "inductance")]
+pub mod inductance;
+#[cfg(feature = "irradiance")]
+pub mod irradiance;
 #[cfg(feature = "length")]
 pub mod length;
+#[cfg(feature = "level")]
+pub mod level;
+#[cfg(feature = "luminous_flux")]
+pub mod luminous_flux;
+#[cfg(feature = "luminous_intensity")]
+pub mod luminous_intensity;
 #[cfg(feature = "mass")]
 pub mod mass;
+#[cfg(feature = "mass_flow_rate")]
+pub mod mass_flow_rate;
+#[cfg(feature = "molar_concentration")]
+pub mod molar_concentration;
+#[cfg(feature = "molar_mass")]
+pub mod molar_mass;
+#[cfg(feature = "money")]
+pub mod money;
 #[cfg(feature = "power")]
 pub mod power;
+#[cfg(feature = "pressure")]
+pub mod pressure;
+#[cfg(feature = "resistance")]
+pub mod resistance;
+#[cfg(feature = "solid_angle")]
+pub mod solid_angle;
 #[cfg(feature = "speed")]
 pub mod speed;
 #[cfg(feature = "temperature")]
 pub mod temperature;
+#[cfg(feature = "torque")]
+pub mod torque;
+#[cfg(feature = "voltage")]
+pub mod voltage;
 #[cfg(feature = "volume")]
 pub mod volume;
+#[cfg(feature = "volumetric_flow_rate")]
+pub mod volumetric_flow_rate;
 
 /// The abstract type of units used to define quantities.
 pub trait Unit:
@@ -252,6 +354,20 @@ pub trait Quantity: Copy + Sized + Mul<AmountT> {
     /// Returns a new instance of the type implementing `Quantity`.
     fn new(amount: AmountT, unit: Self::UnitType) -> Self;
 
+    /// Returns the rate of `self` per `per`.
+    ///
+    /// This is useful for ratios of quantities which have no declared
+    /// derived relation, like a price per mass.
+    ///
+    /// It is provided as a method instead of an operator `TQ / PQ`, because
+    /// a generic impl of `Div` would conflict with the impls of `Div`
+    /// generated for quantities and their derived quantities.
+    #[must_use]
+    #[inline(always)]
+    fn per<PQ: Quantity>(self, per: PQ) -> Rate<Self, PQ> {
+        Rate::from_qty_vals(self, per)
+    }
+
     /// Returns the amount of `self`.
     fn amount(&self) -> AmountT;
 
@@ -383,7 +499,8 @@ where
     const REF_UNIT: <Self as Quantity>::UnitType;
 
     /// Returns `Some(unit)` where `unit.scale()` == `amnt`, or `None` if
-    /// there is no such unit.
+    /// there is no such unit. If there is more than one such unit, the one
+    /// defined first is returned.
     #[must_use]
     fn unit_from_scale(amnt: AmountT) -> Option<Self::UnitType> {
         Self::iter_units().find(|&unit| unit.scale() == amnt)
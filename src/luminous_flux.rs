@@ -0,0 +1,80 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `LuminousFlux`.
+
+use crate::{
+    luminous_intensity::LuminousIntensity, prelude::*, solid_angle::SolidAngle,
+};
+
+#[quantity(LuminousIntensity * SolidAngle)]
+#[ref_unit(Lumen, "lm", NONE, "Reference unit of quantity `LuminousFlux`")]
+#[unit(Millilumen, "mlm", MILLI, 0.001, "0.001·lm")]
+#[unit(Kilolumen, "klm", KILO, 1000, "1000·lm")]
+/// Perceived power of light emitted by a source
+///
+/// Definition: LuminousIntensity·SolidAngle
+///
+/// Reference unit: Lumen ('lm' = 'cd·sr')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'lm'  |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | mlm    | Millilumen            | 0.001·lm          | 0.001               |
+/// | klm    | Kilolumen             | 1000·lm           | 1000                |
+pub struct LuminousFlux {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        luminous_intensity::{CANDELA, MILLICANDELA},
+        solid_angle::{SPAT, STERADIAN},
+    };
+
+    #[test]
+    fn test_luminous_flux() {
+        assert_eq!(
+            <LuminousFlux as HasRefUnit>::REF_UNIT,
+            LuminousFluxUnit::REF_UNIT
+        );
+        assert!(LUMEN.is_ref_unit());
+        let amnt: AmountT = Amnt!(1.6);
+        let f = amnt * KILOLUMEN;
+        assert_eq!(f.amount, amnt);
+        assert_eq!(f.unit, KILOLUMEN);
+        #[cfg(feature = "std")]
+        assert_eq!(f.to_string(), "1.6 klm");
+        let f = f.convert(LUMEN);
+        assert_almost_eq!(f.amount, Amnt!(1600.));
+    }
+
+    #[test]
+    fn test_intensity_mul_solid_angle() {
+        let ai: AmountT = Amnt!(120.);
+        let i = ai * CANDELA;
+        let ao: AmountT = Amnt!(0.5);
+        let o = ao * STERADIAN;
+        let f = i * o;
+        assert_almost_eq!(f.amount(), ai * ao);
+        assert_eq!(f.unit(), LUMEN);
+        let f = o * i;
+        assert_almost_eq!(f.amount(), ai * ao);
+        let i = f / o;
+        assert_almost_eq!(i.equiv_amount(CANDELA), ai);
+        let o = f / i;
+        assert_almost_eq!(o.equiv_amount(STERADIAN), ao);
+        // isotropic source
+        let i = Amnt!(2000.) * MILLICANDELA;
+        let f = i * (Amnt!(1.) * SPAT);
+        assert_almost_eq!(f.equiv_amount(LUMEN), Amnt!(25.132741228718345));
+    }
+}
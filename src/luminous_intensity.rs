@@ -0,0 +1,57 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of basic quantity `LuminousIntensity`.
+
+use crate::prelude::*;
+
+#[quantity]
+#[ref_unit(
+    Candela,
+    "cd",
+    NONE,
+    "Reference unit of quantity `LuminousIntensity`"
+)]
+#[unit(Millicandela, "mcd", MILLI, 0.001, "0.001·cd")]
+#[unit(Kilocandela, "kcd", KILO, 1000, "1000·cd")]
+/// Luminous power emitted by a light source in a particular direction per
+/// unit solid angle
+///
+/// Reference unit: Candela ('cd')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'cd'  |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | mcd    | Millicandela          | 0.001·cd          | 0.001               |
+/// | kcd    | Kilocandela           | 1000·cd           | 1000                |
+pub struct LuminousIntensity {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_almost_eq;
+
+    #[test]
+    fn test_luminous_intensity() {
+        assert_eq!(
+            <LuminousIntensity as HasRefUnit>::REF_UNIT,
+            LuminousIntensityUnit::REF_UNIT
+        );
+        assert!(CANDELA.is_ref_unit());
+        let amnt: AmountT = Amnt!(850.);
+        let i = amnt * MILLICANDELA;
+        assert_eq!(i.amount, amnt);
+        assert_eq!(i.unit, MILLICANDELA);
+        #[cfg(feature = "std")]
+        assert_eq!(i.to_string(), "850 mcd");
+        let i = i.convert(CANDELA);
+        assert_almost_eq!(i.amount, Amnt!(0.85));
+    }
+}
@@ -0,0 +1,158 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `MassFlowRate`.
+
+#[cfg(all(feature = "density", feature = "volumetric_flow_rate"))]
+use crate::{density::Density, volumetric_flow_rate::VolumetricFlowRate};
+use crate::{duration::Duration, mass::Mass, prelude::*};
+
+#[quantity(Mass / Duration)]
+#[ref_unit(
+    Kilogram_per_Second,
+    "kg/s",
+    NONE,
+    "Reference unit of quantity `MassFlowRate`"
+)]
+#[unit(Pound_per_Hour, "lb/h", 0.000125997880555556, "lb/h")]
+#[unit(Kilogram_per_Hour, "kg/h", 0.000277777777777778, "kg/h")]
+#[unit(Gram_per_Second, "g/s", 0.001, "g/s")]
+#[unit(Pound_per_Minute, "lb/min", 0.007559872833333333, "lb/min")]
+#[unit(Kilogram_per_Minute, "kg/min", 0.016666666666666667, "kg/min")]
+#[unit(Tonne_per_Hour, "t/h", 0.2777777777777778, "t/h")]
+#[unit(Pound_per_Second, "lb/s", 0.45359237, "lb/s")]
+/// Mass of a substance passing per unit of time
+///
+/// Definition: Mass/Duration
+///
+/// Reference unit: Kilogram per Second ('kg/s')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition | Equiv. in 'kg/s'     |
+/// |--------|-----------------------|------------|----------------------|
+/// | lb/h   | Pound per Hour        | lb/h       | 0.000125997880555556 |
+/// | kg/h   | Kilogram per Hour     | kg/h       | 0.000277777777777778 |
+/// | g/s    | Gram per Second       | g/s        | 0.001                |
+/// | lb/min | Pound per Minute      | lb/min     | 0.007559872833333333 |
+/// | kg/min | Kilogram per Minute   | kg/min     | 0.016666666666666667 |
+/// | t/h    | Tonne per Hour        | t/h        | 0.2777777777777778   |
+/// | lb/s   | Pound per Second      | lb/s       | 0.45359237           |
+pub struct MassFlowRate {}
+
+#[cfg(all(feature = "density", feature = "volumetric_flow_rate"))]
+impl Mul<VolumetricFlowRate> for Density {
+    type Output = MassFlowRate;
+
+    fn mul(self, rhs: VolumetricFlowRate) -> Self::Output {
+        let scale = self.unit().scale() * rhs.unit().scale();
+        match Self::Output::unit_from_scale(scale) {
+            Some(unit) => {
+                Self::Output::new(self.amount() * rhs.amount(), unit)
+            }
+            None => Self::Output::_fit(self.amount() * rhs.amount() * scale),
+        }
+    }
+}
+
+#[cfg(all(feature = "density", feature = "volumetric_flow_rate"))]
+impl Mul<Density> for VolumetricFlowRate {
+    type Output = MassFlowRate;
+
+    #[inline(always)]
+    fn mul(self, rhs: Density) -> Self::Output {
+        rhs * self
+    }
+}
+
+#[cfg(all(feature = "density", feature = "volumetric_flow_rate"))]
+impl Div<Density> for MassFlowRate {
+    type Output = VolumetricFlowRate;
+
+    fn div(self, rhs: Density) -> Self::Output {
+        let scale = self.unit().scale() / rhs.unit().scale();
+        match Self::Output::unit_from_scale(scale) {
+            Some(unit) => {
+                Self::Output::new(self.amount() / rhs.amount(), unit)
+            }
+            None => Self::Output::_fit(self.amount() / rhs.amount() * scale),
+        }
+    }
+}
+
+#[cfg(all(feature = "density", feature = "volumetric_flow_rate"))]
+impl Div<VolumetricFlowRate> for MassFlowRate {
+    type Output = Density;
+
+    fn div(self, rhs: VolumetricFlowRate) -> Self::Output {
+        let scale = self.unit().scale() / rhs.unit().scale();
+        match Self::Output::unit_from_scale(scale) {
+            Some(unit) => {
+                Self::Output::new(self.amount() / rhs.amount(), unit)
+            }
+            None => Self::Output::_fit(self.amount() / rhs.amount() * scale),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        duration::{HOUR, MINUTE},
+        mass::{KILOGRAM, TONNE},
+    };
+
+    #[test]
+    fn test_mass_div_duration() {
+        let am: AmountT = Amnt!(37.5);
+        let m = am * KILOGRAM;
+        let at: AmountT = Amnt!(1.5);
+        let t = at * HOUR;
+        let r = m / t;
+        assert_almost_eq!(r.equiv_amount(KILOGRAM_PER_HOUR), am / at);
+        let r = r.convert(KILOGRAM_PER_MINUTE);
+        assert_almost_eq!(r.amount(), am / at / Amnt!(60.));
+        assert_eq!(r.unit(), KILOGRAM_PER_MINUTE);
+    }
+
+    #[test]
+    fn test_mass_flow_rate_mul_duration() {
+        let ar: AmountT = Amnt!(2.4);
+        let r = ar * TONNE_PER_HOUR;
+        let at: AmountT = Amnt!(45.);
+        let t = at * MINUTE;
+        let m = r * t;
+        assert_almost_eq!(m.equiv_amount(TONNE), ar * at / Amnt!(60.));
+        let t = m / r;
+        assert_almost_eq!(t.equiv_amount(MINUTE), at);
+    }
+
+    #[cfg(all(feature = "density", feature = "volumetric_flow_rate"))]
+    #[test]
+    fn test_density_mul_volumetric_flow_rate() {
+        use crate::{
+            density::KILOGRAM_PER_LITER,
+            volumetric_flow_rate::LITER_PER_SECOND,
+        };
+        let ad: AmountT = Amnt!(0.85);
+        let d = ad * KILOGRAM_PER_LITER;
+        let av: AmountT = Amnt!(4.);
+        let v = av * LITER_PER_SECOND;
+        let r = d * v;
+        assert_almost_eq!(r.equiv_amount(KILOGRAM_PER_SECOND), ad * av);
+        let r = v * d;
+        assert_almost_eq!(r.equiv_amount(KILOGRAM_PER_SECOND), ad * av);
+        let v = r / d;
+        assert_almost_eq!(v.equiv_amount(LITER_PER_SECOND), av);
+        let d = r / v;
+        assert_almost_eq!(d.equiv_amount(KILOGRAM_PER_LITER), ad);
+    }
+}
@@ -0,0 +1,89 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `MolarConcentration`.
+
+use crate::{
+    amount_of_substance::AmountOfSubstance, prelude::*, volume::Volume,
+};
+
+#[quantity(AmountOfSubstance / Volume)]
+#[ref_unit(
+    Mole_per_Cubic_Meter,
+    "mol/m³",
+    NONE,
+    "Reference unit of quantity `MolarConcentration`"
+)]
+#[unit(Micromole_per_Liter, "µmol/l", 0.001, "µmol/l")]
+#[unit(Millimole_per_Liter, "mmol/l", 1, "mmol/l")]
+#[unit(Mole_per_Liter, "mol/l", 1000, "mol/l")]
+/// Amount of a substance per unit of volume of a solution
+///
+/// Definition: AmountOfSubstance/Volume
+///
+/// Reference unit: Mole per Cubic Meter ('mol/m³')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equiv. in 'mol/m³' |
+/// |--------|-----------------------|-------------------|--------------------|
+/// | µmol/l | Micromole per Liter   | µmol/l            | 0.001              |
+/// | mmol/l | Millimole per Liter   | mmol/l            | 1                  |
+/// | mol/l  | Mole per Liter        | mol/l             | 1000               |
+pub struct MolarConcentration {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        amount_of_substance::{MILLIMOLE, MOLE},
+        assert_almost_eq,
+        volume::{LITER, MILLILITER},
+    };
+
+    #[test]
+    fn test_molar_concentration() {
+        assert_eq!(
+            <MolarConcentration as HasRefUnit>::REF_UNIT,
+            MolarConcentrationUnit::REF_UNIT
+        );
+        assert!(MOLE_PER_CUBIC_METER.is_ref_unit());
+        let c = Amnt!(0.15) * MOLE_PER_LITER;
+        let d = c.convert(MILLIMOLE_PER_LITER);
+        assert_almost_eq!(d.amount(), Amnt!(150.));
+    }
+
+    #[test]
+    fn test_amount_of_substance_div_volume() {
+        let an: AmountT = Amnt!(0.5);
+        let n = an * MOLE;
+        let av: AmountT = Amnt!(2.);
+        let v = av * LITER;
+        let c = n / v;
+        assert_almost_eq!(c.equiv_amount(MOLE_PER_LITER), an / av);
+        let n = Amnt!(7.5) * MILLIMOLE;
+        let v = Amnt!(1.5) * LITER;
+        let c = n / v;
+        assert_almost_eq!(c.equiv_amount(MILLIMOLE_PER_LITER), Amnt!(5.));
+    }
+
+    #[test]
+    fn test_molar_concentration_mul_volume() {
+        let ac: AmountT = Amnt!(0.1);
+        let c = ac * MOLE_PER_LITER;
+        let av: AmountT = Amnt!(250.);
+        let v = av * MILLILITER;
+        let n = c * v;
+        assert_almost_eq!(n.equiv_amount(MILLIMOLE), Amnt!(25.));
+        let n = v * c;
+        assert_almost_eq!(n.equiv_amount(MILLIMOLE), Amnt!(25.));
+        let v = n / c;
+        assert_almost_eq!(v.equiv_amount(MILLILITER), av);
+    }
+}
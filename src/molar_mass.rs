@@ -0,0 +1,85 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `MolarMass`.
+
+use crate::{amount_of_substance::AmountOfSubstance, mass::Mass, prelude::*};
+
+#[quantity(Mass / AmountOfSubstance)]
+#[ref_unit(
+    Kilogram_per_Mole,
+    "kg/mol",
+    NONE,
+    "Reference unit of quantity `MolarMass`"
+)]
+#[unit(Gram_per_Mole, "g/mol", 0.001, "g/mol")]
+/// Mass of a substance per amount of that substance
+///
+/// Definition: Mass/AmountOfSubstance
+///
+/// Reference unit: Kilogram per Mole ('kg/mol')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equiv. in 'kg/mol' |
+/// |--------|-----------------------|-------------------|--------------------|
+/// | g/mol  | Gram per Mole         | g/mol             | 0.001              |
+pub struct MolarMass {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        amount_of_substance::{MILLIMOLE, MOLE},
+        assert_almost_eq,
+        mass::{GRAM, MILLIGRAM},
+    };
+
+    #[test]
+    fn test_molar_mass() {
+        assert_eq!(
+            <MolarMass as HasRefUnit>::REF_UNIT,
+            MolarMassUnit::REF_UNIT
+        );
+        assert!(KILOGRAM_PER_MOLE.is_ref_unit());
+        let m = Amnt!(18.015) * GRAM_PER_MOLE;
+        let n = m.convert(KILOGRAM_PER_MOLE);
+        assert_almost_eq!(n.amount(), Amnt!(0.018015));
+    }
+
+    #[test]
+    fn test_mass_div_amount_of_substance() {
+        let am: AmountT = Amnt!(117.);
+        let m = am * GRAM;
+        let an: AmountT = Amnt!(2.);
+        let n = an * MOLE;
+        let mm = m / n;
+        assert_almost_eq!(mm.amount(), am / an);
+        assert_eq!(mm.unit(), GRAM_PER_MOLE);
+    }
+
+    #[test]
+    fn test_molar_mass_mul_amount_of_substance() {
+        let amm: AmountT = Amnt!(58.44);
+        let mm = amm * GRAM_PER_MOLE;
+        let an: AmountT = Amnt!(0.25);
+        let n = an * MOLE;
+        let m = mm * n;
+        assert_almost_eq!(m.amount(), amm * an);
+        assert_eq!(m.unit(), GRAM);
+        let m = n * mm;
+        assert_almost_eq!(m.amount(), amm * an);
+        let n = m / mm;
+        assert_almost_eq!(n.amount(), an);
+        assert_eq!(n.unit(), MOLE);
+        let n = Amnt!(3.) * MILLIMOLE;
+        let m = mm * n;
+        assert_almost_eq!(m.equiv_amount(MILLIGRAM), amm * Amnt!(3.));
+    }
+}
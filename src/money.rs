@@ -0,0 +1,333 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of basic quantity `Money` and of the converter
+//! `ExchangeRates`.
+
+use alloc::vec::Vec;
+
+use crate::{
+    amnt_round, converter::find_conversion, prelude::*, Converter, AMNT_ZERO,
+};
+
+#[quantity]
+#[unit(Australian_Dollar, "AUD", "Australian Dollar")]
+#[unit(Bahraini_Dinar, "BHD", "Bahraini Dinar")]
+#[unit(Brazilian_Real, "BRL", "Brazilian Real")]
+#[unit(Canadian_Dollar, "CAD", "Canadian Dollar")]
+#[unit(Swiss_Franc, "CHF", "Swiss Franc")]
+#[unit(Chilean_Peso, "CLP", "Chilean Peso")]
+#[unit(Yuan_Renminbi, "CNY", "Yuan Renminbi")]
+#[unit(Czech_Koruna, "CZK", "Czech Koruna")]
+#[unit(Danish_Krone, "DKK", "Danish Krone")]
+#[unit(Euro, "EUR", "Euro")]
+#[unit(Pound_Sterling, "GBP", "Pound Sterling")]
+#[unit(Hong_Kong_Dollar, "HKD", "Hong Kong Dollar")]
+#[unit(Forint, "HUF", "Forint")]
+#[unit(Indian_Rupee, "INR", "Indian Rupee")]
+#[unit(Iceland_Krona, "ISK", "Iceland Krona")]
+#[unit(Yen, "JPY", "Yen")]
+#[unit(Won, "KRW", "Won")]
+#[unit(Kuwaiti_Dinar, "KWD", "Kuwaiti Dinar")]
+#[unit(Mexican_Peso, "MXN", "Mexican Peso")]
+#[unit(Norwegian_Krone, "NOK", "Norwegian Krone")]
+#[unit(New_Zealand_Dollar, "NZD", "New Zealand Dollar")]
+#[unit(Zloty, "PLN", "Zloty")]
+#[unit(Swedish_Krona, "SEK", "Swedish Krona")]
+#[unit(Singapore_Dollar, "SGD", "Singapore Dollar")]
+#[unit(Turkish_Lira, "TRY", "Turkish Lira")]
+#[unit(US_Dollar, "USD", "US Dollar")]
+#[unit(Rand, "ZAR", "Rand")]
+/// Amount of money in some currency
+///
+/// As there is no fixed relation between the currencies, `Money` has no
+/// reference unit. Amounts in different currencies can't be added,
+/// subtracted or compared; they have to be converted first, for example via
+/// [`ExchangeRates`].
+///
+/// The units are named and identified by the codes defined in ISO 4217.
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Minor unit digits |
+/// |--------|-----------------------|-------------------|
+/// | AUD    | Australian Dollar     | 2                 |
+/// | BHD    | Bahraini Dinar        | 3                 |
+/// | BRL    | Brazilian Real        | 2                 |
+/// | CAD    | Canadian Dollar       | 2                 |
+/// | CHF    | Swiss Franc           | 2                 |
+/// | CLP    | Chilean Peso          | 0                 |
+/// | CNY    | Yuan Renminbi         | 2                 |
+/// | CZK    | Czech Koruna          | 2                 |
+/// | DKK    | Danish Krone          | 2                 |
+/// | EUR    | Euro                  | 2                 |
+/// | GBP    | Pound Sterling        | 2                 |
+/// | HKD    | Hong Kong Dollar      | 2                 |
+/// | HUF    | Forint                | 2                 |
+/// | INR    | Indian Rupee          | 2                 |
+/// | ISK    | Iceland Krona         | 0                 |
+/// | JPY    | Yen                   | 0                 |
+/// | KRW    | Won                   | 0                 |
+/// | KWD    | Kuwaiti Dinar         | 3                 |
+/// | MXN    | Mexican Peso          | 2                 |
+/// | NOK    | Norwegian Krone       | 2                 |
+/// | NZD    | New Zealand Dollar    | 2                 |
+/// | PLN    | Zloty                 | 2                 |
+/// | SEK    | Swedish Krona         | 2                 |
+/// | SGD    | Singapore Dollar      | 2                 |
+/// | TRY    | Turkish Lira          | 2                 |
+/// | USD    | US Dollar             | 2                 |
+/// | ZAR    | Rand                  | 2                 |
+pub struct Money {}
+
+#[allow(clippy::multiple_inherent_impl)]
+impl MoneyUnit {
+    /// Returns the number of fractional digits of the minor unit of `self`
+    /// (as defined in ISO 4217), for example 2 for EUR (1 EUR = 100 cent) or
+    /// 0 for JPY.
+    #[must_use]
+    pub const fn minor_unit_digits(&self) -> u8 {
+        match self {
+            Self::ChileanPeso | Self::IcelandKrona | Self::Yen | Self::Won => {
+                0
+            }
+            Self::BahrainiDinar | Self::KuwaitiDinar => 3,
+            _ => 2,
+        }
+    }
+}
+
+#[allow(clippy::multiple_inherent_impl)]
+impl Money {
+    /// Returns `self` with its amount rounded to the minor unit of its
+    /// currency.
+    #[must_use]
+    pub fn round_to_minor_unit(&self) -> Self {
+        Self::new(
+            amnt_round(self.amount(), self.unit().minor_unit_digits()),
+            self.unit(),
+        )
+    }
+}
+
+/// Error returned when an exchange rate is not positive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidExchangeRateError;
+
+impl fmt::Display for InvalidExchangeRateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("exchange rate must be positive")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidExchangeRateError {}
+
+/// A table of exchange rates between currencies, valid at a given date.
+///
+/// Each rate `r` given for a pair of currencies (`from_unit`, `to_unit`)
+/// defines the conversion
+/// to_amount = from_amount * r
+///
+/// The inverse conversion and conversions between currencies not related by
+/// a single rate are derived from the given rates, so that rates relative
+/// to a single base currency are sufficient to define all conversions.
+///
+/// The type of the date `D` is up to the user. If it implements `Ord`, the
+/// table valid at a given date can be selected from a set of tables via
+/// [`ExchangeRates::valid_at`].
+#[derive(Clone, Debug)]
+pub struct ExchangeRates<D> {
+    date: D,
+    mappings: Vec<(MoneyUnit, MoneyUnit, AmountT, AmountT)>,
+}
+
+impl<D> ExchangeRates<D> {
+    /// Returns a new `ExchangeRates` table for `date` holding the tuples
+    /// (from_unit, to_unit, rate) given in `rates`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidExchangeRateError`] if any of the given rates is not
+    /// positive.
+    pub fn new(
+        date: D,
+        rates: &[(MoneyUnit, MoneyUnit, AmountT)],
+    ) -> Result<Self, InvalidExchangeRateError> {
+        let mut mappings = Vec::with_capacity(rates.len());
+        for (from_unit, to_unit, rate) in rates {
+            if *rate > AMNT_ZERO {
+                mappings.push((*from_unit, *to_unit, *rate, AMNT_ZERO));
+            } else {
+                return Err(InvalidExchangeRateError);
+            }
+        }
+        Ok(Self { date, mappings })
+    }
+
+    /// Returns the date `self` is valid at.
+    #[inline(always)]
+    pub const fn date(&self) -> &D {
+        &self.date
+    }
+
+    /// Returns the rate `r` so that
+    /// to_amount = from_amount * r
+    /// defines the conversion from `from_unit` to `to_unit`, or `None` if
+    /// there is no such conversion.
+    #[must_use]
+    pub fn rate(
+        &self,
+        from_unit: MoneyUnit,
+        to_unit: MoneyUnit,
+    ) -> Option<AmountT> {
+        find_conversion(&self.mappings, from_unit, to_unit)
+            .map(|(factor, _)| factor)
+    }
+}
+
+impl<D: Ord> ExchangeRates<D> {
+    /// Returns the table from `tables` which is valid at `date`, i.e. the
+    /// one with the latest date not after `date`, or `None` if there is no
+    /// such table.
+    pub fn valid_at<'a>(tables: &'a [Self], date: &D) -> Option<&'a Self> {
+        tables
+            .iter()
+            .filter(|table| table.date <= *date)
+            .max_by(|lhs, rhs| lhs.date.cmp(&rhs.date))
+    }
+}
+
+impl<D> Converter<Money> for ExchangeRates<D> {
+    fn convert(&self, qty: &Money, to_unit: MoneyUnit) -> Option<Money> {
+        if qty.unit() == to_unit {
+            return Some(*qty);
+        }
+        self.rate(qty.unit(), to_unit)
+            .map(|rate| Money::new(qty.amount() * rate, to_unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_almost_eq;
+
+    #[test]
+    fn test_money() {
+        let amnt: AmountT = Amnt!(17.5);
+        let m = amnt * EURO;
+        assert_eq!(m.amount, amnt);
+        assert_eq!(m.unit, EURO);
+        #[cfg(feature = "std")]
+        assert_eq!(m.to_string(), "17.5 EUR");
+        assert_eq!(US_DOLLAR.name(), "US Dollar");
+        assert_eq!(US_DOLLAR.symbol(), "USD");
+        let n = m + Amnt!(2.5) * EURO;
+        assert_eq!(n.amount, Amnt!(20));
+        assert!(m < n);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_different_currencies() {
+        let _ = Amnt!(1) * EURO + Amnt!(1) * US_DOLLAR;
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(EURO.minor_unit_digits(), 2);
+        assert_eq!(YEN.minor_unit_digits(), 0);
+        assert_eq!(KUWAITI_DINAR.minor_unit_digits(), 3);
+        let m = Amnt!(12.3456) * EURO;
+        assert_eq!(m.round_to_minor_unit(), Amnt!(12.35) * EURO);
+        let m = Amnt!(-1234.5) * YEN;
+        assert_eq!(m.round_to_minor_unit(), Amnt!(-1234) * YEN);
+        let m = Amnt!(0.0625) * BAHRAINI_DINAR;
+        assert_eq!(m.round_to_minor_unit(), Amnt!(0.062) * BAHRAINI_DINAR);
+    }
+
+    fn rates() -> ExchangeRates<(u16, u8, u8)> {
+        ExchangeRates::new(
+            (2026, 10, 1),
+            &[
+                (EURO, US_DOLLAR, Amnt!(1.25)),
+                (EURO, SWISS_FRANC, Amnt!(0.9375)),
+                (EURO, YEN, Amnt!(160)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_exchange_rates() {
+        let rates = rates();
+        assert_eq!(*rates.date(), (2026, 10, 1));
+        let m = Amnt!(20) * EURO;
+        let n = rates.convert(&m, US_DOLLAR).unwrap();
+        assert_eq!(n.unit, US_DOLLAR);
+        assert_almost_eq!(n.amount, Amnt!(25));
+        let m = rates.convert(&n, EURO).unwrap();
+        assert_almost_eq!(m.amount, Amnt!(20));
+        let m = Amnt!(400) * YEN;
+        let n = rates.convert(&m, SWISS_FRANC).unwrap();
+        assert_almost_eq!(n.amount, Amnt!(2.34375));
+        assert_eq!(n.round_to_minor_unit(), Amnt!(2.34) * SWISS_FRANC);
+        assert!(rates.convert(&m, POUND_STERLING).is_none());
+        assert_eq!(rates.rate(EURO, EURO), Some(Amnt!(1)));
+    }
+
+    #[test]
+    fn test_non_positive_rate() {
+        assert_eq!(
+            ExchangeRates::new((), &[(EURO, US_DOLLAR, Amnt!(0))]).err(),
+            Some(InvalidExchangeRateError)
+        );
+        assert!(ExchangeRates::new(
+            (),
+            &[(EURO, US_DOLLAR, Amnt!(1.25)), (EURO, YEN, Amnt!(-160))]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_dated_exchange_rates() {
+        let tables = [
+            rates(),
+            ExchangeRates::new((2026, 9, 1), &[(EURO, US_DOLLAR, Amnt!(1.2))])
+                .unwrap(),
+            ExchangeRates::new((2026, 11, 1), &[(EURO, US_DOLLAR, Amnt!(1))])
+                .unwrap(),
+        ];
+        let rates = ExchangeRates::valid_at(&tables, &(2026, 10, 15)).unwrap();
+        assert_eq!(*rates.date(), (2026, 10, 1));
+        let rates = ExchangeRates::valid_at(&tables, &(2026, 9, 30)).unwrap();
+        assert_eq!(rates.rate(EURO, US_DOLLAR), Some(Amnt!(1.2)));
+        assert!(ExchangeRates::valid_at(&tables, &(2026, 8, 31)).is_none());
+    }
+
+    #[cfg(feature = "mass")]
+    #[test]
+    fn test_price() {
+        use crate::mass::{Mass, GRAM, KILOGRAM};
+
+        let price =
+            Rate::<Money, Mass>::new(Amnt!(2.5), EURO, Amnt!(1), KILOGRAM);
+        #[cfg(feature = "std")]
+        assert_eq!(price.to_string(), "2.5 EUR / kg");
+        let m = price * (Amnt!(1500) * GRAM);
+        assert_eq!(m.unit, EURO);
+        assert_almost_eq!(m.amount, Amnt!(3.75));
+        let usd_price = Rate::<Money, Mass>::from_qty_vals(
+            rates().convert(&(Amnt!(2.5) * EURO), US_DOLLAR).unwrap(),
+            Amnt!(1) * KILOGRAM,
+        );
+        assert_almost_eq!(usd_price.term_amount(), Amnt!(3.125));
+    }
+}
@@ -14,6 +14,9 @@ use crate::{duration::Duration, energy::Energy, prelude::*};
 #[quantity(Energy / Duration)]
 #[ref_unit(Watt, "W", NONE, "Reference unit of quantity `Power`")]
 #[unit(Milliwatt, "mW", MILLI, 0.001, "0.001·W")]
+#[unit(IT_Btu_per_Hour, "Btu_IT/h", 0.2930710701722222, "Btu_IT/h")]
+#[unit(Metric_Horsepower, "PS", 735.49875, "75·kgf·m/s")]
+#[unit(Mechanical_Horsepower, "hp", 745.6998715822702, "550·ft·lbf/s")]
 #[unit(Kilowatt, "kW", KILO, 1000, "1000·W")]
 #[unit(Megawatt, "MW", MEGA, 1000000, "1000000·W")]
 #[unit(Gigawatt, "GW", GIGA, 1000000000, "1000000000·W")]
@@ -26,13 +29,16 @@ use crate::{duration::Duration, energy::Energy, prelude::*};
 ///
 /// Predefined units:
 ///
-/// | Symbol | Name                  | Definition        | Equivalent in 'W'   |
-/// |--------|-----------------------|-------------------|---------------------|
-/// | mW     | Milliwatt             | 0.001·W           | 0.001               |
-/// | kW     | Kilowatt              | 1000·W            | 1000                |
-/// | MW     | Megawatt              | 1000000·W         | 1000000             |
-/// | GW     | Gigawatt              | 1000000000·W      | 1000000000          |
-/// | TW     | Terawatt              | 1000000000000·W   | 1000000000000       |
+/// | Symbol   | Name                  | Definition      | Equivalent in 'W'  |
+/// |----------|-----------------------|-----------------|--------------------|
+/// | mW       | Milliwatt             | 0.001·W         | 0.001              |
+/// | Btu_IT/h | IT Btu per Hour       | Btu_IT/h        | 0.2930710701722222 |
+/// | PS       | Metric Horsepower     | 75·kgf·m/s      | 735.49875          |
+/// | hp       | Mechanical Horsepower | 550·ft·lbf/s    | 745.6998715822702  |
+/// | kW       | Kilowatt              | 1000·W          | 1000               |
+/// | MW       | Megawatt              | 1000000·W       | 1000000            |
+/// | GW       | Gigawatt              | 1000000000·W    | 1000000000         |
+/// | TW       | Terawatt              | 1000000000000·W | 1000000000000      |
 pub struct Power {}
 
 #[cfg(test)]
@@ -41,7 +47,7 @@ mod tests {
     use crate::{
         assert_almost_eq,
         duration::{HOUR, MINUTE},
-        energy::KILOWATT_HOUR,
+        energy::{IT_BRITISH_THERMAL_UNIT, KILOWATT_HOUR},
     };
 
     #[test]
@@ -65,4 +71,17 @@ mod tests {
         assert_almost_eq!(t.amount(), ae / ap);
         assert_eq!(t.unit(), HOUR);
     }
+
+    #[test]
+    fn test_customary_units() {
+        let p = Amnt!(1.) * MECHANICAL_HORSEPOWER;
+        let q = p.convert(KILOWATT);
+        assert_almost_eq!(q.amount(), Amnt!(0.7456998715822702));
+        let q = p.convert(METRIC_HORSEPOWER);
+        assert_almost_eq!(q.amount(), Amnt!(1.013869665424));
+        let e = Amnt!(3.) * IT_BRITISH_THERMAL_UNIT;
+        let t = Amnt!(1.) * HOUR;
+        let p = e / t;
+        assert_almost_eq!(p.equiv_amount(IT_BTU_PER_HOUR), Amnt!(3.));
+    }
 }
@@ -0,0 +1,103 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Pressure`.
+
+use crate::{area::Area, force::Force, prelude::*};
+
+#[quantity(Force / Area)]
+#[ref_unit(Pascal, "Pa", NONE, "Reference unit of quantity `Pressure`")]
+#[unit(Hectopascal, "hPa", HECTO, 100, "100·Pa")]
+#[unit(Millibar, "mbar", 100, "0.001·bar")]
+#[unit(Torr, "Torr", 133.32236842105263, "101325/760·Pa")]
+#[unit(Millimeter_of_Mercury, "mmHg", 133.322387415, "133.322387415·Pa")]
+#[unit(Kilopascal, "kPa", KILO, 1000, "1000·Pa")]
+#[unit(Inch_of_Mercury, "inHg", 3386.388640341, "3386.388640341·Pa")]
+#[unit(Pound_per_Square_Inch, "psi", 6894.757293168361, "lbf/in²")]
+#[unit(Bar, "bar", 100000, "100000·Pa")]
+#[unit(Atmosphere, "atm", 101325, "101325·Pa")]
+#[unit(Megapascal, "MPa", MEGA, 1000000, "1000000·Pa")]
+/// Force applied perpendicular to the surface of an object per unit area
+///
+/// Definition: Force/Area
+///
+/// Reference unit: Pascal ('Pa' = 'N/m²' = 'kg/(m·s²)')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'Pa'  |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | hPa    | Hectopascal           | 100·Pa            | 100                 |
+/// | mbar   | Millibar              | 0.001·bar         | 100                 |
+/// | Torr   | Torr                  | 101325/760·Pa     | 133.32236842105263  |
+/// | mmHg   | Millimeter of Mercury | 133.322387415·Pa  | 133.322387415       |
+/// | kPa    | Kilopascal            | 1000·Pa           | 1000                |
+/// | inHg   | Inch of Mercury       | 3386.388640341·Pa | 3386.388640341      |
+/// | psi    | Pound per Square Inch | lbf/in²           | 6894.757293168361   |
+/// | bar    | Bar                   | 100000·Pa         | 100000              |
+/// | atm    | Atmosphere            | 101325·Pa         | 101325              |
+/// | MPa    | Megapascal            | 1000000·Pa        | 1000000             |
+///
+/// Hectopascal and Millibar have the same scale; where a unit is selected by
+/// its scale, for example as result of `Force / Area`, Hectopascal is used.
+pub struct Pressure {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        area::{SQUARE_CENTIMETER, SQUARE_METER},
+        assert_almost_eq,
+        force::NEWTON,
+    };
+
+    #[test]
+    fn test_pressure() {
+        assert_eq!(<Pressure as HasRefUnit>::REF_UNIT, PressureUnit::REF_UNIT);
+        assert!(PASCAL.is_ref_unit());
+        let p = Amnt!(1.) * ATMOSPHERE;
+        let q = p.convert(TORR);
+        assert_almost_eq!(q.amount(), Amnt!(760.));
+        let q = p.convert(POUND_PER_SQUARE_INCH);
+        assert_almost_eq!(q.amount(), Amnt!(14.695948775513449));
+        let q = p.convert(MILLIBAR);
+        assert_almost_eq!(q.amount(), Amnt!(1013.25));
+    }
+
+    #[test]
+    fn test_unit_from_scale() {
+        assert_eq!(Pressure::unit_from_scale(Amnt!(100)), Some(HECTOPASCAL));
+        assert_eq!(Pressure::unit_from_scale(Amnt!(1000)), Some(KILOPASCAL));
+        assert_eq!(Pressure::unit_from_scale(Amnt!(2)), None);
+    }
+
+    #[test]
+    fn test_force_div_area() {
+        let af: AmountT = Amnt!(500.);
+        let f = af * NEWTON;
+        let aa: AmountT = Amnt!(25.);
+        let a = aa * SQUARE_CENTIMETER;
+        let p = f / a;
+        assert_almost_eq!(p.equiv_amount(PASCAL), af / aa * Amnt!(10000.));
+    }
+
+    #[test]
+    fn test_pressure_mul_area() {
+        let ap: AmountT = Amnt!(2.5);
+        let p = ap * KILOPASCAL;
+        let aa: AmountT = Amnt!(3.);
+        let a = aa * SQUARE_METER;
+        let f = p * a;
+        assert_almost_eq!(f.equiv_amount(NEWTON), ap * aa * Amnt!(1000.));
+        let f = a * p;
+        assert_almost_eq!(f.equiv_amount(NEWTON), ap * aa * Amnt!(1000.));
+        let a = f / p;
+        assert_almost_eq!(a.equiv_amount(SQUARE_METER), aa);
+    }
+}
@@ -0,0 +1,416 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! N-dimensional arrays of quantity values, based on crate `ndarray`.
+//!
+//! A [`QtyArrayBase`] wraps an `ndarray::ArrayBase` holding the amounts of
+//! the values, together with a single unit shared by all of them.
+
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use ndarray::{
+    Array, ArrayBase, Axis, Data, DataMut, DataOwned, DimMax, Dimension, Ix1,
+    NdIndex, OwnedRepr, RawData, RawDataClone, RemoveAxis, ViewRepr,
+};
+
+use crate::{
+    amnt_from_usize, qty_vec::QtyVec, AmountT, HasRefUnit, LinearScaledUnit,
+    Quantity, AMNT_ZERO,
+};
+
+/// N-dimensional array of values of quantity `Q`, sharing a common unit.
+///
+/// The amounts are held in an `ndarray::ArrayBase` with data representation
+/// `S` and dimension `D`.
+pub struct QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: RawData<Elem = AmountT>,
+    D: Dimension,
+{
+    amounts: ArrayBase<S, D>,
+    unit: Q::UnitType,
+}
+
+/// Owned array of values of quantity `Q`.
+pub type QtyArray<Q, D> = QtyArrayBase<Q, OwnedRepr<AmountT>, D>;
+
+/// Read-only view of an array of values of quantity `Q`.
+pub type QtyArrayView<'a, Q, D> = QtyArrayBase<Q, ViewRepr<&'a AmountT>, D>;
+
+impl<Q, S, D> QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: RawData<Elem = AmountT>,
+    D: Dimension,
+{
+    /// Returns a new instance of `QtyArrayBase` with the given `amounts`
+    /// and `unit`.
+    #[inline(always)]
+    pub const fn new(amounts: ArrayBase<S, D>, unit: Q::UnitType) -> Self {
+        Self { amounts, unit }
+    }
+
+    /// Returns the amounts of `self`.
+    #[inline(always)]
+    pub const fn amounts(&self) -> &ArrayBase<S, D> {
+        &self.amounts
+    }
+
+    /// Consumes `self`, returning its amounts.
+    #[inline(always)]
+    pub fn into_amounts(self) -> ArrayBase<S, D> {
+        self.amounts
+    }
+
+    /// Returns the unit of `self`.
+    #[inline(always)]
+    pub const fn unit(&self) -> Q::UnitType {
+        self.unit
+    }
+
+    /// Returns the shape of `self`.
+    #[inline(always)]
+    pub fn shape(&self) -> &[usize] {
+        self.amounts.shape()
+    }
+
+    /// Returns the total number of values in `self`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns `true` if `self` contains no values.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+}
+
+impl<Q, S, D> QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    /// Returns a read-only view of `self`.
+    pub fn view(&self) -> QtyArrayView<'_, Q, D> {
+        QtyArrayBase::new(self.amounts.view(), self.unit)
+    }
+
+    /// Returns an owned array holding a copy of the values in `self`.
+    pub fn to_owned(&self) -> QtyArray<Q, D> {
+        QtyArrayBase::new(self.amounts.to_owned(), self.unit)
+    }
+
+    /// Returns the value at `index`, or `None` if `index` is out of bounds.
+    pub fn get<I: NdIndex<D>>(&self, index: I) -> Option<Q> {
+        self.amounts.get(index).map(|&amnt| Q::new(amnt, self.unit))
+    }
+
+    /// Returns an iterator over the values in `self`, in logical order.
+    pub fn iter(&self) -> impl Iterator<Item = Q> + '_ {
+        let unit = self.unit;
+        self.amounts.iter().map(move |&amnt| Q::new(amnt, unit))
+    }
+
+    /// Returns the sum of all values in `self`.
+    pub fn sum(&self) -> Q {
+        Q::new(
+            self.amounts.fold(AMNT_ZERO, |sum, &amnt| sum + amnt),
+            self.unit,
+        )
+    }
+
+    /// Returns the arithmetic mean of all values in `self`, or `None` if
+    /// `self` is empty.
+    pub fn mean(&self) -> Option<Q> {
+        (!self.is_empty()).then(|| {
+            Q::new(
+                self.sum().amount() / amnt_from_usize(self.len()),
+                self.unit,
+            )
+        })
+    }
+
+    /// Returns the smallest value in `self`, or `None` if `self` is empty.
+    pub fn min(&self) -> Option<Q> {
+        self.amounts
+            .iter()
+            .copied()
+            .reduce(|min, amnt| if amnt < min { amnt } else { min })
+            .map(|amnt| Q::new(amnt, self.unit))
+    }
+
+    /// Returns the greatest value in `self`, or `None` if `self` is empty.
+    pub fn max(&self) -> Option<Q> {
+        self.amounts
+            .iter()
+            .copied()
+            .reduce(|max, amnt| if amnt > max { amnt } else { max })
+            .map(|amnt| Q::new(amnt, self.unit))
+    }
+
+    /// Returns the sums of the values in `self` along `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is out of bounds.
+    pub fn sum_axis(&self, axis: Axis) -> QtyArray<Q, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        QtyArrayBase::new(
+            self.amounts
+                .fold_axis(axis, AMNT_ZERO, |&sum, &amnt| sum + amnt),
+            self.unit,
+        )
+    }
+
+    /// Returns the arithmetic means of the values in `self` along `axis`,
+    /// or `None` if the length of `axis` is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is out of bounds.
+    pub fn mean_axis(&self, axis: Axis) -> Option<QtyArray<Q, D::Smaller>>
+    where
+        D: RemoveAxis,
+    {
+        let len = self.amounts.len_of(axis);
+        (len > 0).then(|| self.sum_axis(axis) / amnt_from_usize(len))
+    }
+}
+
+impl<Q, S, D> QtyArrayBase<Q, S, D>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    S: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    /// Returns an owned array holding the values in `self` converted to
+    /// `to_unit`.
+    pub fn convert(&self, to_unit: Q::UnitType) -> QtyArray<Q, D> {
+        if self.unit == to_unit {
+            self.to_owned()
+        } else {
+            let factor = self.unit.ratio(&to_unit);
+            QtyArrayBase::new(self.amounts.mapv(|amnt| amnt * factor), to_unit)
+        }
+    }
+}
+
+impl<Q, S, D> QtyArrayBase<Q, S, D>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    S: DataMut<Elem = AmountT>,
+    D: Dimension,
+{
+    /// Converts all values in `self` to `to_unit`.
+    pub fn convert_assign(&mut self, to_unit: Q::UnitType) {
+        if self.unit != to_unit {
+            let factor = self.unit.ratio(&to_unit);
+            self.amounts.mapv_inplace(|amnt| amnt * factor);
+            self.unit = to_unit;
+        }
+    }
+}
+
+impl<Q, S, D> Clone for QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: RawDataClone<Elem = AmountT>,
+    D: Dimension,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.amounts.clone(), self.unit)
+    }
+}
+
+impl<Q, S, D> fmt::Debug for QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    Q::UnitType: fmt::Debug,
+    S: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QtyArrayBase")
+            .field("amounts", &self.amounts)
+            .field("unit", &self.unit)
+            .finish()
+    }
+}
+
+impl<Q, S, S2, D> PartialEq<QtyArrayBase<Q, S2, D>> for QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: Data<Elem = AmountT>,
+    S2: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    /// Returns `true` if `self` and `other` have the same shape, the same
+    /// amounts and the same unit.
+    fn eq(&self, other: &QtyArrayBase<Q, S2, D>) -> bool {
+        self.unit == other.unit && self.amounts == other.amounts
+    }
+}
+
+impl<Q: Quantity> From<QtyVec<Q>> for QtyArray<Q, Ix1> {
+    fn from(qties: QtyVec<Q>) -> Self {
+        let unit = qties.unit();
+        Self::new(Array::from_vec(qties.into_amounts()), unit)
+    }
+}
+
+impl<Q, S, D> Mul<AmountT> for QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: DataOwned<Elem = AmountT> + DataMut,
+    D: Dimension,
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: AmountT) -> Self::Output {
+        self.amounts.mapv_inplace(|amnt| amnt * rhs);
+        self
+    }
+}
+
+impl<Q, S, D> Mul<AmountT> for &QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    type Output = QtyArray<Q, D>;
+
+    fn mul(self, rhs: AmountT) -> Self::Output {
+        QtyArrayBase::new(self.amounts.mapv(|amnt| amnt * rhs), self.unit)
+    }
+}
+
+impl<Q, S, D> Div<AmountT> for QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: DataOwned<Elem = AmountT> + DataMut,
+    D: Dimension,
+{
+    type Output = Self;
+
+    fn div(mut self, rhs: AmountT) -> Self::Output {
+        self.amounts.mapv_inplace(|amnt| amnt / rhs);
+        self
+    }
+}
+
+impl<Q, S, D> Div<AmountT> for &QtyArrayBase<Q, S, D>
+where
+    Q: Quantity,
+    S: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    type Output = QtyArray<Q, D>;
+
+    fn div(self, rhs: AmountT) -> Self::Output {
+        QtyArrayBase::new(self.amounts.mapv(|amnt| amnt / rhs), self.unit)
+    }
+}
+
+impl<Q, S, D> Add<Q> for &QtyArrayBase<Q, S, D>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    S: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    type Output = QtyArray<Q, D>;
+
+    fn add(self, rhs: Q) -> Self::Output {
+        let rhs = rhs.equiv_amount(self.unit);
+        QtyArrayBase::new(self.amounts.mapv(|amnt| amnt + rhs), self.unit)
+    }
+}
+
+impl<Q, S, D> Sub<Q> for &QtyArrayBase<Q, S, D>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    S: Data<Elem = AmountT>,
+    D: Dimension,
+{
+    type Output = QtyArray<Q, D>;
+
+    fn sub(self, rhs: Q) -> Self::Output {
+        let rhs = rhs.equiv_amount(self.unit);
+        QtyArrayBase::new(self.amounts.mapv(|amnt| amnt - rhs), self.unit)
+    }
+}
+
+impl<'a, Q, S, S2, D, E> Add<&'a QtyArrayBase<Q, S2, E>>
+    for &'a QtyArrayBase<Q, S, D>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    S: Data<Elem = AmountT>,
+    S2: Data<Elem = AmountT>,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    type Output = QtyArray<Q, <D as DimMax<E>>::Output>;
+
+    /// Returns the elementwise sum of `self` and `rhs`, broadcasting them to
+    /// a common shape, in the unit of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if broadcasting is not possible.
+    fn add(self, rhs: &'a QtyArrayBase<Q, S2, E>) -> Self::Output {
+        let amounts = if self.unit == rhs.unit {
+            &self.amounts + &rhs.amounts
+        } else {
+            &self.amounts + &rhs.convert(self.unit).amounts
+        };
+        QtyArrayBase::new(amounts, self.unit)
+    }
+}
+
+impl<'a, Q, S, S2, D, E> Sub<&'a QtyArrayBase<Q, S2, E>>
+    for &'a QtyArrayBase<Q, S, D>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    S: Data<Elem = AmountT>,
+    S2: Data<Elem = AmountT>,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    type Output = QtyArray<Q, <D as DimMax<E>>::Output>;
+
+    /// Returns the elementwise difference of `self` and `rhs`, broadcasting
+    /// them to a common shape, in the unit of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if broadcasting is not possible.
+    fn sub(self, rhs: &'a QtyArrayBase<Q, S2, E>) -> Self::Output {
+        let amounts = if self.unit == rhs.unit {
+            &self.amounts - &rhs.amounts
+        } else {
+            &self.amounts - &rhs.convert(self.unit).amounts
+        };
+        QtyArrayBase::new(amounts, self.unit)
+    }
+}
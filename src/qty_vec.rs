@@ -0,0 +1,496 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Sequences of quantity values sharing a common unit.
+//!
+//! [`QtyVec`] and [`QtySlice`] store the amounts of the values contiguously,
+//! together with a single unit. Operations on them are done on the amounts
+//! as a whole, so they are well suited for bulk numeric processing.
+
+use alloc::vec::Vec;
+use core::{
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    },
+    slice::SliceIndex,
+};
+
+use crate::{AmountT, HasRefUnit, LinearScaledUnit, Quantity};
+
+/// Borrowed sequence of values of quantity `Q` sharing a common unit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QtySlice<'a, Q: Quantity> {
+    amounts: &'a [AmountT],
+    unit: Q::UnitType,
+}
+
+/// Owned sequence of values of quantity `Q` sharing a common unit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QtyVec<Q: Quantity> {
+    amounts: Vec<AmountT>,
+    unit: Q::UnitType,
+}
+
+impl<'a, Q: Quantity> QtySlice<'a, Q> {
+    /// Returns a new instance of `QtySlice` with the given `amounts` and
+    /// `unit`.
+    #[inline(always)]
+    pub const fn new(amounts: &'a [AmountT], unit: Q::UnitType) -> Self {
+        Self { amounts, unit }
+    }
+
+    /// Returns the amounts of `self`.
+    #[inline(always)]
+    pub const fn amounts(&self) -> &'a [AmountT] {
+        self.amounts
+    }
+
+    /// Returns the unit of `self`.
+    #[inline(always)]
+    pub const fn unit(&self) -> Q::UnitType {
+        self.unit
+    }
+
+    /// Returns the number of values in `self`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns `true` if `self` contains no values.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    /// Returns the value at position `idx`, or `None` if `idx` is out of
+    /// bounds.
+    pub fn get(&self, idx: usize) -> Option<Q> {
+        self.amounts.get(idx).map(|&amnt| Q::new(amnt, self.unit))
+    }
+
+    /// Returns the sub-slice of `self` given by `range`, or `None` if
+    /// `range` is out of bounds.
+    pub fn slice<R>(&self, range: R) -> Option<Self>
+    where
+        R: SliceIndex<[AmountT], Output = [AmountT]>,
+    {
+        self.amounts
+            .get(range)
+            .map(|amounts| QtySlice::new(amounts, self.unit))
+    }
+
+    /// Returns an iterator over the values in `self`.
+    pub fn iter(&self) -> impl Iterator<Item = Q> + 'a
+    where
+        Q::UnitType: 'a,
+    {
+        let unit = self.unit;
+        self.amounts.iter().map(move |&amnt| Q::new(amnt, unit))
+    }
+
+    /// Returns a `QtyVec` holding a copy of the values in `self`.
+    pub fn to_qty_vec(&self) -> QtyVec<Q> {
+        QtyVec::from_amounts(self.amounts.to_vec(), self.unit)
+    }
+
+    /// Returns a `Vec` holding the values in `self`.
+    pub fn to_vec(&self) -> Vec<Q> {
+        self.iter().collect()
+    }
+}
+
+impl<Q: HasRefUnit> QtySlice<'_, Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// Returns a `QtyVec` holding the values in `self` converted to
+    /// `to_unit`.
+    pub fn convert(&self, to_unit: Q::UnitType) -> QtyVec<Q> {
+        let mut res = self.to_qty_vec();
+        res.convert_assign(to_unit);
+        res
+    }
+}
+
+impl<Q: Quantity> QtyVec<Q> {
+    /// Returns a new, empty instance of `QtyVec` with the given `unit`.
+    #[inline(always)]
+    pub const fn new(unit: Q::UnitType) -> Self {
+        Self {
+            amounts: Vec::new(),
+            unit,
+        }
+    }
+
+    /// Returns a new, empty instance of `QtyVec` with the given `unit` and
+    /// at least the given `capacity`.
+    pub fn with_capacity(capacity: usize, unit: Q::UnitType) -> Self {
+        Self {
+            amounts: Vec::with_capacity(capacity),
+            unit,
+        }
+    }
+
+    /// Returns a new instance of `QtyVec` with the given `amounts` and
+    /// `unit`.
+    #[inline(always)]
+    pub const fn from_amounts(
+        amounts: Vec<AmountT>,
+        unit: Q::UnitType,
+    ) -> Self {
+        Self { amounts, unit }
+    }
+
+    /// Returns the amounts of `self`.
+    #[inline(always)]
+    pub fn amounts(&self) -> &[AmountT] {
+        &self.amounts
+    }
+
+    /// Returns the amounts of `self` as mutable slice.
+    #[inline(always)]
+    pub fn amounts_mut(&mut self) -> &mut [AmountT] {
+        &mut self.amounts
+    }
+
+    /// Consumes `self`, returning its amounts.
+    #[inline(always)]
+    pub fn into_amounts(self) -> Vec<AmountT> {
+        self.amounts
+    }
+
+    /// Returns the unit of `self`.
+    #[inline(always)]
+    pub const fn unit(&self) -> Q::UnitType {
+        self.unit
+    }
+
+    /// Returns the number of values in `self`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns `true` if `self` contains no values.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    /// Returns a `QtySlice` containing all values in `self`.
+    #[inline(always)]
+    pub fn as_slice(&self) -> QtySlice<'_, Q> {
+        QtySlice::new(&self.amounts, self.unit)
+    }
+
+    /// Returns the value at position `idx`, or `None` if `idx` is out of
+    /// bounds.
+    pub fn get(&self, idx: usize) -> Option<Q> {
+        self.as_slice().get(idx)
+    }
+
+    /// Returns the sub-slice of `self` given by `range`, or `None` if
+    /// `range` is out of bounds.
+    pub fn slice<R>(&self, range: R) -> Option<QtySlice<'_, Q>>
+    where
+        R: SliceIndex<[AmountT], Output = [AmountT]>,
+    {
+        self.as_slice().slice(range)
+    }
+
+    /// Returns an iterator over the values in `self`.
+    pub fn iter(&self) -> impl Iterator<Item = Q> + '_ {
+        self.as_slice().iter()
+    }
+}
+
+impl<Q: HasRefUnit> QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// Appends `qty`, converted to the unit of `self`.
+    pub fn push(&mut self, qty: Q) {
+        self.amounts.push(qty.equiv_amount(self.unit));
+    }
+
+    /// Returns a `QtyVec` holding the values in `self` converted to
+    /// `to_unit`.
+    pub fn convert(&self, to_unit: Q::UnitType) -> Self {
+        self.as_slice().convert(to_unit)
+    }
+
+    /// Converts all values in `self` to `to_unit`.
+    pub fn convert_assign(&mut self, to_unit: Q::UnitType) {
+        if self.unit != to_unit {
+            let factor = self.unit.ratio(&to_unit);
+            self.amounts.iter_mut().for_each(|amnt| *amnt *= factor);
+            self.unit = to_unit;
+        }
+    }
+
+    // Applies `op` to each amount in `self` and the corresponding amount of
+    // `rhs`, converted to the unit of `self`.
+    fn zip_apply(
+        &mut self,
+        rhs: QtySlice<'_, Q>,
+        op: impl Fn(&mut AmountT, AmountT),
+    ) {
+        assert_eq!(
+            self.len(),
+            rhs.len(),
+            "Can't combine sequences of different length."
+        );
+        if self.unit == rhs.unit {
+            self.amounts
+                .iter_mut()
+                .zip(rhs.amounts)
+                .for_each(|(lhs, &rhs)| op(lhs, rhs));
+        } else {
+            let factor = rhs.unit.ratio(&self.unit);
+            self.amounts
+                .iter_mut()
+                .zip(rhs.amounts)
+                .for_each(|(lhs, &rhs)| op(lhs, rhs * factor));
+        }
+    }
+}
+
+impl<Q: Quantity> From<QtyVec<Q>> for Vec<Q> {
+    fn from(qties: QtyVec<Q>) -> Self {
+        qties.iter().collect()
+    }
+}
+
+impl<'a, Q: Quantity> From<&'a QtyVec<Q>> for QtySlice<'a, Q> {
+    fn from(qties: &'a QtyVec<Q>) -> Self {
+        qties.as_slice()
+    }
+}
+
+impl<Q: HasRefUnit> From<&[Q]> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// Returns a `QtyVec` holding the values in `qties`, converted to the
+    /// unit of the first one (or the reference unit, if `qties` is empty).
+    fn from(qties: &[Q]) -> Self {
+        qties.iter().copied().collect()
+    }
+}
+
+impl<Q: HasRefUnit> From<Vec<Q>> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// Returns a `QtyVec` holding the values in `qties`, converted to the
+    /// unit of the first one (or the reference unit, if `qties` is empty).
+    fn from(qties: Vec<Q>) -> Self {
+        Self::from(qties.as_slice())
+    }
+}
+
+impl<Q: HasRefUnit> FromIterator<Q> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// Returns a `QtyVec` holding the values in `iter`, converted to the
+    /// unit of the first one (or the reference unit, if `iter` is empty).
+    fn from_iter<I: IntoIterator<Item = Q>>(iter: I) -> Self {
+        let mut it = iter.into_iter();
+        match it.next() {
+            Some(first) => {
+                let mut res = Self::with_capacity(
+                    it.size_hint().0.saturating_add(1),
+                    first.unit(),
+                );
+                res.amounts.push(first.amount());
+                it.for_each(|qty| res.push(qty));
+                res
+            }
+            None => Self::new(Q::REF_UNIT),
+        }
+    }
+}
+
+impl<Q: HasRefUnit> Extend<Q> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    fn extend<I: IntoIterator<Item = Q>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|qty| self.push(qty));
+    }
+}
+
+impl<Q: Quantity> MulAssign<AmountT> for QtyVec<Q> {
+    fn mul_assign(&mut self, rhs: AmountT) {
+        self.amounts.iter_mut().for_each(|amnt| *amnt *= rhs);
+    }
+}
+
+impl<Q: Quantity> DivAssign<AmountT> for QtyVec<Q> {
+    fn div_assign(&mut self, rhs: AmountT) {
+        self.amounts.iter_mut().for_each(|amnt| *amnt /= rhs);
+    }
+}
+
+impl<Q: Quantity> Mul<AmountT> for QtyVec<Q> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: AmountT) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<Q: Quantity> Mul<QtyVec<Q>> for AmountT {
+    type Output = QtyVec<Q>;
+
+    fn mul(self, rhs: QtyVec<Q>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<Q: Quantity> Div<AmountT> for QtyVec<Q> {
+    type Output = Self;
+
+    fn div(mut self, rhs: AmountT) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl<Q: Quantity> Mul<AmountT> for QtySlice<'_, Q> {
+    type Output = QtyVec<Q>;
+
+    fn mul(self, rhs: AmountT) -> Self::Output {
+        self.to_qty_vec() * rhs
+    }
+}
+
+impl<Q: Quantity> Div<AmountT> for QtySlice<'_, Q> {
+    type Output = QtyVec<Q>;
+
+    fn div(self, rhs: AmountT) -> Self::Output {
+        self.to_qty_vec() / rhs
+    }
+}
+
+impl<Q: Quantity> Neg for QtyVec<Q> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        self.amounts.iter_mut().for_each(|amnt| *amnt = -*amnt);
+        self
+    }
+}
+
+impl<Q: HasRefUnit> AddAssign<Q> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    fn add_assign(&mut self, rhs: Q) {
+        let rhs = rhs.equiv_amount(self.unit);
+        self.amounts.iter_mut().for_each(|amnt| *amnt += rhs);
+    }
+}
+
+impl<Q: HasRefUnit> SubAssign<Q> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    fn sub_assign(&mut self, rhs: Q) {
+        let rhs = rhs.equiv_amount(self.unit);
+        self.amounts.iter_mut().for_each(|amnt| *amnt -= rhs);
+    }
+}
+
+impl<Q: HasRefUnit> AddAssign<QtySlice<'_, Q>> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` differ in length.
+    fn add_assign(&mut self, rhs: QtySlice<'_, Q>) {
+        self.zip_apply(rhs, |lhs, rhs| *lhs += rhs);
+    }
+}
+
+impl<Q: HasRefUnit> SubAssign<QtySlice<'_, Q>> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` differ in length.
+    fn sub_assign(&mut self, rhs: QtySlice<'_, Q>) {
+        self.zip_apply(rhs, |lhs, rhs| *lhs -= rhs);
+    }
+}
+
+impl<Q: HasRefUnit> AddAssign<&Self> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` differ in length.
+    fn add_assign(&mut self, rhs: &Self) {
+        *self += rhs.as_slice();
+    }
+}
+
+impl<Q: HasRefUnit> SubAssign<&Self> for QtyVec<Q>
+where
+    Q::UnitType: LinearScaledUnit,
+{
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` differ in length.
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self -= rhs.as_slice();
+    }
+}
+
+macro_rules! impl_binop_via_assign {
+    ($op_trait:ident, $op_fn:ident, $op_assign_fn:ident, $rhs:ty) => {
+        impl<Q: HasRefUnit> $op_trait<$rhs> for QtyVec<Q>
+        where
+            Q::UnitType: LinearScaledUnit,
+        {
+            type Output = Self;
+
+            fn $op_fn(mut self, rhs: $rhs) -> Self::Output {
+                self.$op_assign_fn(rhs);
+                self
+            }
+        }
+
+        impl<Q: HasRefUnit> $op_trait<$rhs> for QtySlice<'_, Q>
+        where
+            Q::UnitType: LinearScaledUnit,
+        {
+            type Output = QtyVec<Q>;
+
+            fn $op_fn(self, rhs: $rhs) -> Self::Output {
+                let mut res = self.to_qty_vec();
+                res.$op_assign_fn(rhs);
+                res
+            }
+        }
+    };
+}
+
+impl_binop_via_assign!(Add, add, add_assign, Q);
+impl_binop_via_assign!(Sub, sub, sub_assign, Q);
+impl_binop_via_assign!(Add, add, add_assign, QtySlice<'_, Q>);
+impl_binop_via_assign!(Sub, sub, sub_assign, QtySlice<'_, Q>);
+impl_binop_via_assign!(Add, add, add_assign, &QtyVec<Q>);
+impl_binop_via_assign!(Sub, sub, sub_assign, &QtyVec<Q>);
@@ -0,0 +1,376 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Ranges of quantity values.
+//!
+//! [`QtyRange`] represents a closed interval like "0 °C – 40 °C",
+//! [`Tolerance`] a nominal value with a symmetric deviation like
+//! "10 mm ± 0.1 mm". All comparisons are done via [`PartialOrd`], so the
+//! bounds of a range of a quantity with a reference unit may be given in
+//! different units, whereas the values of a quantity without a reference
+//! unit (like `Temperature`) must share a common unit.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::{amnt_from_usize, AmountT, Quantity, AMNT_ZERO};
+
+/// Error returned when a string can't be parsed as `QtyRange` or
+/// `Tolerance`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseRangeError;
+
+impl fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid range of quantity values")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRangeError {}
+
+// Returns the amount and the unit symbol given in `s`, which must have the
+// form `<amount> <unit symbol>` or `<amount>`.
+fn split_qty(s: &str) -> Result<(AmountT, &str), ParseRangeError> {
+    let (amnt, symbol) = s
+        .trim()
+        .split_once(char::is_whitespace)
+        .unwrap_or((s.trim(), ""));
+    let amnt: AmountT = amnt.parse().map_err(|_| ParseRangeError)?;
+    Ok((amnt, symbol.trim_start()))
+}
+
+// Returns the quantity value represented by `s`, which must have the form
+// `<amount> <unit symbol>`, or `<amount>` for a unit with an empty symbol.
+fn parse_qty<Q: Quantity>(s: &str) -> Result<Q, ParseRangeError> {
+    let (amnt, symbol) = split_qty(s)?;
+    let unit = Q::unit_from_symbol(symbol).ok_or(ParseRangeError)?;
+    Ok(Q::new(amnt, unit))
+}
+
+// Returns `qty` in the given `unit`. For quantities without a reference
+// unit, `qty` must already have that unit.
+#[inline]
+fn in_unit<Q>(qty: Q, unit: Q::UnitType) -> Q
+where
+    Q: Quantity + Add<Output = Q>,
+{
+    Q::new(AMNT_ZERO, unit) + qty
+}
+
+#[inline]
+fn is_le<Q: PartialOrd>(lhs: &Q, rhs: &Q) -> bool {
+    matches!(lhs.partial_cmp(rhs), Some(Ordering::Less | Ordering::Equal))
+}
+
+// Returns the lesser one of `lhs` and `rhs` (`lhs` if both are equal), or
+// `None` if they can't be compared.
+#[inline]
+fn lesser<Q: PartialOrd>(lhs: Q, rhs: Q) -> Option<Q> {
+    match rhs.partial_cmp(&lhs)? {
+        Ordering::Less => Some(rhs),
+        _ => Some(lhs),
+    }
+}
+
+// Returns the greater one of `lhs` and `rhs` (`lhs` if both are equal), or
+// `None` if they can't be compared.
+#[inline]
+fn greater<Q: PartialOrd>(lhs: Q, rhs: Q) -> Option<Q> {
+    match lhs.partial_cmp(&rhs)? {
+        Ordering::Less => Some(rhs),
+        _ => Some(lhs),
+    }
+}
+
+/// Closed interval of values of quantity `Q`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QtyRange<Q: Quantity> {
+    start: Q,
+    end: Q,
+}
+
+impl<Q> QtyRange<Q>
+where
+    Q: Quantity + PartialOrd + Add<Output = Q> + Sub<Output = Q>,
+{
+    /// Returns a new instance of `QtyRange` from `start` to `end`, both
+    /// inclusive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `end` or if both can't be compared
+    /// (i.e. they have different units and `Q` has no reference unit).
+    pub fn new(start: Q, end: Q) -> Self {
+        assert!(
+            is_le(&start, &end),
+            "Start of range must not be greater than its end."
+        );
+        Self { start, end }
+    }
+
+    /// Returns a new instance of `QtyRange` from `nominal` - `delta` to
+    /// `nominal` + `delta`, in the unit of `nominal`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delta` is negative or if `Q` has no reference unit and
+    /// `delta` has a different unit than `nominal`.
+    pub fn from_nominal(nominal: Q, delta: Q) -> Self {
+        Self::new(nominal - delta, nominal + delta)
+    }
+
+    /// Returns the lower bound of `self`.
+    #[inline(always)]
+    pub const fn start(&self) -> Q {
+        self.start
+    }
+
+    /// Returns the upper bound of `self`.
+    #[inline(always)]
+    pub const fn end(&self) -> Q {
+        self.end
+    }
+
+    /// Returns the difference between the upper and the lower bound of
+    /// `self`, in the unit of the lower bound.
+    pub fn width(&self) -> Q {
+        in_unit(self.end, self.start.unit()) - self.start
+    }
+
+    /// Returns `true` if `qty` is within `self`, otherwise `false`.
+    ///
+    /// If `Q` has no reference unit, `qty` is only considered to be within
+    /// `self` if it has the same unit as the bounds of `self`.
+    pub fn contains(&self, qty: &Q) -> bool {
+        is_le(&self.start, qty) && is_le(qty, &self.end)
+    }
+
+    /// Returns the range of values contained in both `self` and `other`, or
+    /// `None` if they are disjoint or their bounds can't be compared.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = greater(self.start, other.start)?;
+        let end = lesser(self.end, other.end)?;
+        is_le(&start, &end).then_some(Self { start, end })
+    }
+
+    /// Returns the smallest range containing both `self` and `other`, or
+    /// `None` if they are disjoint, so that their union is not a range, or
+    /// if their bounds can't be compared.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if !is_le(&other.start, &self.end) || !is_le(&self.start, &other.end) {
+            return None;
+        }
+        Some(Self {
+            start: lesser(self.start, other.start)?,
+            end: greater(self.end, other.end)?,
+        })
+    }
+
+    /// Returns `qty` limited to `self`, in the unit of `qty`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `qty` can't be compared to the bounds of `self`.
+    pub fn clamp(&self, qty: Q) -> Q {
+        match (
+            PartialOrd::partial_cmp(&qty, &self.start),
+            PartialOrd::partial_cmp(&qty, &self.end),
+        ) {
+            (Some(Ordering::Less), Some(_)) => in_unit(self.start, qty.unit()),
+            (Some(_), Some(Ordering::Greater)) => {
+                in_unit(self.end, qty.unit())
+            }
+            (Some(_), Some(_)) => qty,
+            _ => panic!("Value must be comparable to the bounds of range."),
+        }
+    }
+
+    /// Returns an iterator over the values from the lower bound of `self`
+    /// up to its upper bound in increments of `step`, in the unit of the
+    /// lower bound.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive or if `Q` has no reference unit and
+    /// `step` has a different unit than the lower bound.
+    pub fn steps(&self, step: Q) -> impl Iterator<Item = Q> {
+        let unit = self.start.unit();
+        let start = self.start.amount();
+        let step = in_unit(step, unit).amount();
+        assert!(step > AMNT_ZERO, "Step must be positive.");
+        let end = self.end;
+        let mut n = 0_usize;
+        core::iter::from_fn(move || {
+            let qty = Q::new(start + amnt_from_usize(n) * step, unit);
+            n += 1;
+            is_le(&qty, &end).then_some(qty)
+        })
+        .fuse()
+    }
+}
+
+impl<Q> fmt::Display for QtyRange<Q>
+where
+    Q: Quantity + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.start, f)?;
+        f.write_str(" – ")?;
+        fmt::Display::fmt(&self.end, f)
+    }
+}
+
+// Returns the bounds of the range represented by `lower` and `upper`. If
+// `lower` has no unit symbol, it is taken to have the unit of `upper`.
+fn parse_bounds<Q: Quantity>(
+    lower: &str,
+    upper: &str,
+) -> Result<(Q, Q), ParseRangeError> {
+    let end: Q = parse_qty(upper)?;
+    let (amnt, symbol) = split_qty(lower)?;
+    let unit = if symbol.is_empty() {
+        end.unit()
+    } else {
+        Q::unit_from_symbol(symbol).ok_or(ParseRangeError)?
+    };
+    Ok((Q::new(amnt, unit), end))
+}
+
+impl<Q> FromStr for QtyRange<Q>
+where
+    Q: Quantity + PartialOrd,
+{
+    type Err = ParseRangeError;
+
+    /// Returns the range represented by `s`, which must have the form
+    /// `<start> – <end>`, each bound given as `<amount> <unit symbol>`. The
+    /// bounds may be separated by an en dash or a hyphen-minus ('-'), and
+    /// the unit symbol may be omitted from the lower bound, if both bounds
+    /// have the same unit, as in "0–40 °C".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A dash may also be the sign of an amount or of an exponent, so
+        // the first one separating two valid bounds is taken.
+        let (start, end) = s
+            .char_indices()
+            .filter(|&(idx, c)| idx > 0 && (c == '–' || c == '-'))
+            .find_map(|(idx, c)| {
+                parse_bounds(&s[..idx], &s[idx + c.len_utf8()..]).ok()
+            })
+            .ok_or(ParseRangeError)?;
+        if !is_le(&start, &end) {
+            return Err(ParseRangeError);
+        }
+        Ok(Self { start, end })
+    }
+}
+
+/// Nominal value of quantity `Q` with a permissible symmetric deviation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tolerance<Q: Quantity> {
+    nominal: Q,
+    delta: Q,
+}
+
+impl<Q> Tolerance<Q>
+where
+    Q: Quantity + PartialOrd + Add<Output = Q> + Sub<Output = Q>,
+{
+    /// Returns a new instance of `Tolerance` with the given `nominal` value
+    /// and permissible deviation `delta`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delta` is negative.
+    pub fn new(nominal: Q, delta: Q) -> Self {
+        assert!(
+            delta.amount() >= AMNT_ZERO,
+            "Deviation must not be negative."
+        );
+        Self { nominal, delta }
+    }
+
+    /// Returns the nominal value of `self`.
+    #[inline(always)]
+    pub const fn nominal(&self) -> Q {
+        self.nominal
+    }
+
+    /// Returns the permissible deviation of `self`.
+    #[inline(always)]
+    pub const fn delta(&self) -> Q {
+        self.delta
+    }
+
+    /// Returns the smallest permissible value, in the unit of the nominal
+    /// value.
+    pub fn min(&self) -> Q {
+        self.nominal - self.delta
+    }
+
+    /// Returns the greatest permissible value, in the unit of the nominal
+    /// value.
+    pub fn max(&self) -> Q {
+        self.nominal + self.delta
+    }
+
+    /// Returns the range of permissible values.
+    pub fn range(&self) -> QtyRange<Q> {
+        QtyRange {
+            start: self.min(),
+            end: self.max(),
+        }
+    }
+
+    /// Returns `true` if `qty` is within the permissible values, otherwise
+    /// `false`.
+    pub fn contains(&self, qty: &Q) -> bool {
+        self.range().contains(qty)
+    }
+}
+
+impl<Q> From<Tolerance<Q>> for QtyRange<Q>
+where
+    Q: Quantity + PartialOrd + Add<Output = Q> + Sub<Output = Q>,
+{
+    fn from(tolerance: Tolerance<Q>) -> Self {
+        tolerance.range()
+    }
+}
+
+impl<Q> fmt::Display for Tolerance<Q>
+where
+    Q: Quantity + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.nominal, f)?;
+        f.write_str(" ± ")?;
+        fmt::Display::fmt(&self.delta, f)
+    }
+}
+
+impl<Q: Quantity> FromStr for Tolerance<Q> {
+    type Err = ParseRangeError;
+
+    /// Returns the tolerance represented by `s`, which must have the form
+    /// `<nominal> ± <delta>`, each given as `<amount> <unit symbol>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (nominal, delta) = s.split_once('±').ok_or(ParseRangeError)?;
+        let (nominal, delta): (Q, Q) =
+            (parse_qty(nominal)?, parse_qty(delta)?);
+        if delta.amount() < AMNT_ZERO {
+            return Err(ParseRangeError);
+        }
+        Ok(Self { nominal, delta })
+    }
+}
@@ -8,6 +8,7 @@
 // $Revision$
 
 use core::{
+    borrow::Borrow,
     fmt,
     ops::{Div, Mul},
 };
@@ -85,6 +86,18 @@ impl<TQ: Quantity, PQ: Quantity> Rate<TQ, PQ> {
             self.term_unit(),
         )
     }
+
+    /// Returns an iterator over the results of multiplying `self` with each
+    /// of the quantity values in `qties`.
+    pub fn apply<I>(&self, qties: I) -> impl Iterator<Item = TQ>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<PQ>,
+        PQ: Div<PQ, Output = AmountT>,
+    {
+        let rate = *self;
+        qties.into_iter().map(move |qty| rate * *qty.borrow())
+    }
 }
 
 impl<TQ: Quantity, PQ: Quantity> fmt::Display for Rate<TQ, PQ> {
@@ -126,3 +139,27 @@ where
         Self::Output::new(amnt * self.term_amount(), self.term_unit())
     }
 }
+
+impl<TQ: Quantity, PQ: Quantity> Mul<&PQ> for Rate<TQ, PQ>
+where
+    PQ: Div<PQ, Output = AmountT>,
+{
+    type Output = TQ;
+
+    #[inline(always)]
+    fn mul(self, rhs: &PQ) -> Self::Output {
+        self * *rhs
+    }
+}
+
+impl<TQ: Quantity, PQ: Quantity> Mul<&PQ> for &Rate<TQ, PQ>
+where
+    PQ: Div<PQ, Output = AmountT>,
+{
+    type Output = TQ;
+
+    #[inline(always)]
+    fn mul(self, rhs: &PQ) -> Self::Output {
+        *self * *rhs
+    }
+}
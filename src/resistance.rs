@@ -0,0 +1,69 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Resistance`.
+
+use crate::{electric_current::ElectricCurrent, prelude::*, voltage::Voltage};
+
+#[quantity(Voltage / ElectricCurrent)]
+#[ref_unit(Ohm, "Ω", NONE, "Reference unit of quantity `Resistance`")]
+#[unit(Milliohm, "mΩ", MILLI, 0.001, "0.001·Ω")]
+#[unit(Kiloohm, "kΩ", KILO, 1000, "1000·Ω")]
+#[unit(Megaohm, "MΩ", MEGA, 1000000, "1000000·Ω")]
+#[unit(Gigaohm, "GΩ", GIGA, 1000000000, "1000000000·Ω")]
+/// Opposition to the flow of electric current
+///
+/// Definition: Voltage/ElectricCurrent
+///
+/// Reference unit: Ohm ('Ω' = 'V/A' = 'kg·m²/(s³·A²)')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'Ω'   |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | mΩ     | Milliohm              | 0.001·Ω           | 0.001               |
+/// | kΩ     | Kiloohm               | 1000·Ω            | 1000                |
+/// | MΩ     | Megaohm               | 1000000·Ω         | 1000000             |
+/// | GΩ     | Gigaohm               | 1000000000·Ω      | 1000000000          |
+pub struct Resistance {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        electric_current::{AMPERE, MILLIAMPERE},
+        voltage::VOLT,
+    };
+
+    #[test]
+    fn test_voltage_div_current() {
+        let au: AmountT = Amnt!(5.);
+        let u = au * VOLT;
+        let ai: AmountT = Amnt!(2.5);
+        let i = ai * MILLIAMPERE;
+        let r = u / i;
+        assert_almost_eq!(r.amount(), au / ai);
+        assert_eq!(r.unit(), KILOOHM);
+    }
+
+    #[test]
+    fn test_resistance_mul_current() {
+        let ar: AmountT = Amnt!(470.);
+        let r = ar * OHM;
+        let ai: AmountT = Amnt!(0.02);
+        let i = ai * AMPERE;
+        let u = r * i;
+        assert_almost_eq!(u.amount(), ar * ai);
+        assert_eq!(u.unit(), VOLT);
+        let i = u / r;
+        assert_almost_eq!(i.amount(), ai);
+        assert_eq!(i.unit(), AMPERE);
+    }
+}
@@ -0,0 +1,76 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `SolidAngle`.
+
+// Unit scales must be given as literals, so the constants from
+// `core::f64::consts` can't be used.
+#![allow(clippy::approx_constant)]
+
+use crate::{angle::Angle, prelude::*};
+
+#[quantity(Angle * Angle)]
+#[ref_unit(Steradian, "sr", NONE, "Reference unit of quantity `SolidAngle`")]
+#[unit(Square_Degree, "deg²", 0.000304617419786709, "°·°")]
+#[unit(Spat, "sp", 12.566370614359172, "4π·sr")]
+/// Two-dimensional angle in three-dimensional space, measuring the field of
+/// view from a point
+///
+/// Definition: Angle·Angle
+///
+/// Reference unit: Steradian ('sr' = 'rad²')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition | Equiv. in 'sr'       |
+/// |--------|-----------------------|------------|----------------------|
+/// | deg²   | Square Degree         | °·°        | 0.000304617419786709 |
+/// | sp     | Spat                  | 4π·sr      | 12.566370614359172   |
+pub struct SolidAngle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        amnt_round,
+        angle::{DEGREE, RADIAN},
+        assert_almost_eq,
+    };
+
+    #[test]
+    fn test_solid_angle() {
+        assert_eq!(
+            <SolidAngle as HasRefUnit>::REF_UNIT,
+            SolidAngleUnit::REF_UNIT
+        );
+        assert!(STERADIAN.is_ref_unit());
+        let o = Amnt!(1.) * SPAT;
+        assert_almost_eq!(
+            o.equiv_amount(SQUARE_DEGREE),
+            Amnt!(41252.96124941921)
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(o.to_string(), "1 sp");
+    }
+
+    #[test]
+    fn test_angle_mul_angle() {
+        let a = Amnt!(3.) * DEGREE;
+        let b = Amnt!(2.) * DEGREE;
+        let o = a * b;
+        // the scale of deg² is rounded to 18 fractional digits
+        assert_eq!(amnt_round(o.equiv_amount(SQUARE_DEGREE), 4), Amnt!(6.));
+        let a = Amnt!(0.5) * RADIAN;
+        let o = a * a;
+        assert_eq!(o.unit(), STERADIAN);
+        assert_almost_eq!(o.amount(), Amnt!(0.25));
+        let b = o / a;
+        assert_almost_eq!(b.equiv_amount(RADIAN), Amnt!(0.5));
+    }
+}
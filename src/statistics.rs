@@ -0,0 +1,199 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Statistical reductions of sequences of quantity values.
+//!
+//! The functions accept any sequence of values of a quantity having a
+//! reference unit. The values may have different units; unless stated
+//! otherwise, the result is given in the unit of the first value. All
+//! functions return `None` if the given sequence is empty.
+
+use alloc::vec::Vec;
+use core::{cmp::Ordering, ops::Mul};
+
+#[cfg(feature = "std")]
+use crate::amnt_from_f64;
+use crate::{
+    amnt_from_usize, amnt_to_f64, AmountT, HasRefUnit, LinearScaledUnit,
+    AMNT_ONE, AMNT_ZERO,
+};
+
+/// Returns the arithmetic mean of the values in `qties`.
+pub fn mean<Q, I>(qties: I) -> Option<Q>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    I: IntoIterator<Item = Q>,
+{
+    let mut it = qties.into_iter();
+    let first = it.next()?;
+    let (sum, n) = it.fold((first, 1_usize), |(sum, n), qty| {
+        (HasRefUnit::add(sum, qty), n + 1)
+    });
+    Some(Q::new(sum.amount() / amnt_from_usize(n), sum.unit()))
+}
+
+/// Returns the smallest of the values in `qties`, compared by their
+/// equivalent amounts, in its own unit.
+pub fn min<Q, I>(qties: I) -> Option<Q>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    I: IntoIterator<Item = Q>,
+{
+    qties.into_iter().reduce(|min, qty| {
+        match HasRefUnit::partial_cmp(&qty, &min) {
+            Some(Ordering::Less) => qty,
+            _ => min,
+        }
+    })
+}
+
+/// Returns the greatest of the values in `qties`, compared by their
+/// equivalent amounts, in its own unit.
+pub fn max<Q, I>(qties: I) -> Option<Q>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    I: IntoIterator<Item = Q>,
+{
+    qties.into_iter().reduce(|max, qty| {
+        match HasRefUnit::partial_cmp(&qty, &max) {
+            Some(Ordering::Greater) => qty,
+            _ => max,
+        }
+    })
+}
+
+// Returns the unit of the first value in `qties`, the mean of the values'
+// amounts in that unit and the sum of the squared deviations from that mean
+// together with the number of values (Welford's algorithm).
+fn moments<Q, I>(qties: I) -> Option<(Q::UnitType, AmountT, AmountT, AmountT)>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    I: IntoIterator<Item = Q>,
+{
+    let mut it = qties.into_iter();
+    let first = it.next()?;
+    let unit = first.unit();
+    let (mean, m2, n) = it.fold(
+        (first.amount(), AMNT_ZERO, 1_usize),
+        |(mean, m2, n), qty| {
+            let amnt = qty.equiv_amount(unit);
+            let n = n + 1;
+            let delta = amnt - mean;
+            let mean = mean + delta / amnt_from_usize(n);
+            (mean, m2 + delta * (amnt - mean), n)
+        },
+    );
+    Some((unit, mean, m2, amnt_from_usize(n)))
+}
+
+/// Returns the (population) variance of the values in `qties`.
+///
+/// The result has the type of the product of two values of `Q`, for example
+/// `Area` for values of `Length`.
+pub fn variance<Q, I>(qties: I) -> Option<<Q as Mul>::Output>
+where
+    Q: HasRefUnit + Mul,
+    Q::UnitType: LinearScaledUnit,
+    <Q as Mul>::Output: Mul<AmountT, Output = <Q as Mul>::Output>,
+    I: IntoIterator<Item = Q>,
+{
+    let (unit, _, m2, n) = moments(qties)?;
+    let unit_squared = Q::new(AMNT_ONE, unit) * Q::new(AMNT_ONE, unit);
+    Some(unit_squared * (m2 / n))
+}
+
+/// Returns the (population) standard deviation of the values in `qties`.
+///
+/// # Panics
+///
+/// Panics if the result can not be represented as `AmountT`.
+#[cfg(feature = "std")]
+pub fn std_deviation<Q, I>(qties: I) -> Option<Q>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    I: IntoIterator<Item = Q>,
+{
+    let (unit, _, m2, n) = moments(qties)?;
+    let res = amnt_to_f64(m2 / n).sqrt();
+    match amnt_from_f64(res) {
+        Some(amnt) => Some(Q::new(amnt, unit)),
+        None => panic!("Result not representable as AmountT: {res}"),
+    }
+}
+
+/// Returns the median of the values in `qties`.
+///
+/// If the number of values is even, the median is the mean of the two
+/// middle values. Like [percentile], it returns `None` if any value is not
+/// comparable to the others.
+pub fn median<Q, I>(qties: I) -> Option<Q>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    I: IntoIterator<Item = Q>,
+{
+    percentile(qties, AmountT::from(50_u8))
+}
+
+/// Returns the `p`-th percentile of the values in `qties`, or `None` if
+/// `qties` is empty, `p` is not within 0 and 100 or any of the values is not
+/// comparable (i.e. has an amount of NaN).
+///
+/// The percentile is linearly interpolated between the two closest ranks
+/// and given in the unit of the lower one.
+pub fn percentile<Q, I>(qties: I, p: AmountT) -> Option<Q>
+where
+    Q: HasRefUnit,
+    Q::UnitType: LinearScaledUnit,
+    I: IntoIterator<Item = Q>,
+{
+    if !(AMNT_ZERO..=AmountT::from(100_u8)).contains(&p) {
+        return None;
+    }
+    let mut sorted: Vec<Q> = qties.into_iter().collect();
+    if sorted.is_empty() {
+        return None;
+    }
+    // Values with different units are compared by their equivalent amounts,
+    // so only a value not comparable to itself can break the total order
+    // needed for sorting.
+    if sorted
+        .iter()
+        .any(|qty| HasRefUnit::partial_cmp(qty, qty).is_none())
+    {
+        return None;
+    }
+    sorted.sort_by(|lhs, rhs| {
+        HasRefUnit::partial_cmp(lhs, rhs).unwrap_or(Ordering::Equal)
+    });
+    let rank = p / AmountT::from(100_u8) * amnt_from_usize(sorted.len() - 1);
+    // Find the index of the lower one of the closest ranks. Converting
+    // `rank` to `f64` may round it up to the next integer, so the index
+    // has to be corrected in that case.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let mut idx = amnt_to_f64(rank) as usize;
+    if amnt_from_usize(idx) > rank {
+        idx -= 1;
+    }
+    let lower = sorted[idx];
+    let frac = rank - amnt_from_usize(idx);
+    if frac == AMNT_ZERO {
+        return Some(lower);
+    }
+    let upper = sorted[idx + 1].equiv_amount(lower.unit());
+    Some(Q::new(
+        lower.amount() + (upper - lower.amount()) * frac,
+        lower.unit(),
+    ))
+}
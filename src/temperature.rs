@@ -36,25 +36,14 @@ use crate::{converter::ConversionTable, prelude::*};
 pub struct Temperature {}
 
 /// Temperature conversion table
-pub const TEMPERATURE_CONVERTER: ConversionTable<Temperature, 6> =
+///
+/// The conversions from Fahrenheit and between Kelvin and Fahrenheit are
+/// derived from the given entries.
+pub const TEMPERATURE_CONVERTER: ConversionTable<Temperature, 2> =
     ConversionTable {
         mappings: [
             (KELVIN, DEGREE_CELSIUS, Amnt!(1), Amnt!(-273.15)),
-            (DEGREE_CELSIUS, KELVIN, Amnt!(1), Amnt!(273.15)),
-            (KELVIN, DEGREE_FAHRENHEIT, Amnt!(1.8), Amnt!(-459.67)),
-            (
-                DEGREE_FAHRENHEIT,
-                KELVIN,
-                Amnt!(0.555555555555555556),
-                Amnt!(255.372222222222222222),
-            ),
             (DEGREE_CELSIUS, DEGREE_FAHRENHEIT, Amnt!(1.8), Amnt!(32)),
-            (
-                DEGREE_FAHRENHEIT,
-                DEGREE_CELSIUS,
-                Amnt!(0.555555555555555556),
-                Amnt!(-17.777777777777777778),
-            ),
         ],
     };
 
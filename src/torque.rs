@@ -0,0 +1,119 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Torque`.
+
+use crate::{
+    energy::{Energy, JOULE},
+    force::Force,
+    length::Length,
+    prelude::*,
+};
+
+#[quantity(Force * Length, distinct_from = Energy)]
+#[ref_unit(Newton_Meter, "N·m", NONE, "Reference unit of quantity `Torque`")]
+#[unit(Newton_Centimeter, "N·cm", 0.01, "0.01·N·m")]
+#[unit(Pound_Force_Inch, "lbf·in", 0.1129848290276167, "lbf·in")]
+#[unit(Pound_Force_Foot, "lbf·ft", 1.3558179483314004, "lbf·ft")]
+#[unit(Kilogram_Force_Meter, "kgf·m", 9.80665, "kgf·m")]
+#[unit(Kilonewton_Meter, "kN·m", KILO, 1000, "1000·N·m")]
+/// Rotational equivalent of a linear force
+///
+/// Definition: Force·Length
+///
+/// Torque has the same dimension as `Energy`, but is a distinct quantity.
+/// Therefore, `Force * Length` results in `Energy`; use `Torque::from_product`
+/// to get a torque from a force and a lever arm.
+///
+/// Reference unit: Newton Meter ('N·m' = 'kg·m²/s²')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'N·m' |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | N·cm   | Newton Centimeter     | 0.01·N·m          | 0.01                |
+/// | lbf·in | Pound-force Inch      | lbf·in            | 0.1129848290276167  |
+/// | lbf·ft | Pound-force Foot      | lbf·ft            | 1.3558179483314004  |
+/// | kgf·m  | Kilogram-force Meter  | kgf·m             | 9.80665             |
+/// | kN·m   | Kilonewton Meter      | 1000·N·m          | 1000                |
+pub struct Torque {}
+
+#[allow(clippy::multiple_inherent_impl)]
+impl Torque {
+    /// Returns the work done by `self` when rotating an object over an angle
+    /// of `radians`.
+    #[must_use]
+    pub fn work_over(&self, radians: AmountT) -> Energy {
+        Energy::new(self.equiv_amount(NEWTON_METER) * radians, JOULE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        force::NEWTON,
+        length::{CENTIMETER, METER},
+    };
+
+    #[test]
+    fn test_torque() {
+        assert_eq!(<Torque as HasRefUnit>::REF_UNIT, TorqueUnit::REF_UNIT);
+        assert!(NEWTON_METER.is_ref_unit());
+        let t = Amnt!(100.) * POUND_FORCE_FOOT;
+        let u = t.convert(NEWTON_METER);
+        assert_almost_eq!(u.amount(), Amnt!(135.58179483314004));
+        let u = t.convert(POUND_FORCE_INCH);
+        assert_almost_eq!(u.amount(), Amnt!(1200.));
+    }
+
+    #[test]
+    fn test_torque_from_product() {
+        let af: AmountT = Amnt!(250.);
+        let f = af * NEWTON;
+        let al: AmountT = Amnt!(0.4);
+        let l = al * METER;
+        let t = Torque::from_product(f, l);
+        assert_almost_eq!(t.amount(), af * al);
+        assert_eq!(t.unit(), NEWTON_METER);
+        let l = Amnt!(40.) * CENTIMETER;
+        let t = Torque::from_product(f, l);
+        assert_almost_eq!(t.equiv_amount(NEWTON_METER), af * al);
+        // the product operator results in energy
+        let e: Energy = f * l;
+        assert_almost_eq!(e.equiv_amount(JOULE), af * al);
+    }
+
+    #[test]
+    fn test_torque_div_length_or_force() {
+        let at: AmountT = Amnt!(12.5);
+        let t = at * NEWTON_METER;
+        let al: AmountT = Amnt!(0.25);
+        let l = al * METER;
+        let f = t / l;
+        assert_almost_eq!(f.amount(), at / al);
+        assert_eq!(f.unit(), NEWTON);
+        let l = t / f;
+        assert_almost_eq!(l.amount(), al);
+        assert_eq!(l.unit(), METER);
+    }
+
+    #[test]
+    fn test_work_over() {
+        let t = Amnt!(20.) * NEWTON_METER;
+        let e = t.work_over(Amnt!(3.));
+        assert_almost_eq!(e.amount(), Amnt!(60.));
+        assert_eq!(e.unit(), JOULE);
+        let t = Amnt!(2.) * KILONEWTON_METER;
+        let e = t.work_over(Amnt!(0.5));
+        assert_almost_eq!(e.amount(), Amnt!(1000.));
+        assert_eq!(e.unit(), JOULE);
+    }
+}
@@ -0,0 +1,230 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Two- and three-dimensional vectors of quantity values.
+//!
+//! The components of a vector may have different units. Operations on
+//! vectors are done componentwise, following the rules of the operations on
+//! the components' quantity.
+
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+#[cfg(feature = "std")]
+use crate::{amnt_from_f64, amnt_to_f64};
+use crate::{AmountT, HasRefUnit, LinearScaledUnit, Quantity};
+
+macro_rules! impl_vector {
+    ($vector:ident, $n_dims:literal, $first:ident $(, $comp:ident)+) => {
+        impl<Q: Quantity> $vector<Q> {
+            /// Returns a new instance of
+            #[doc = concat!("`", stringify!($vector), "`")]
+            /// with the given components.
+            #[inline(always)]
+            pub const fn new($first: Q $(, $comp: Q)+) -> Self {
+                Self { $first $(, $comp)+ }
+            }
+
+            /// Returns the components of `self` as array.
+            #[inline(always)]
+            pub const fn components(&self) -> [Q; $n_dims] {
+                [self.$first $(, self.$comp)+]
+            }
+
+            /// Returns the dot product of `self` and `rhs`.
+            ///
+            /// The result has the type of the product of the components'
+            /// quantities, for example `Energy` for a displacement and a
+            /// force.
+            pub fn dot<R: Quantity>(
+                self,
+                rhs: $vector<R>,
+            ) -> <Q as Mul<R>>::Output
+            where
+                Q: Mul<R>,
+                <Q as Mul<R>>::Output: Add<Output = <Q as Mul<R>>::Output>,
+            {
+                self.$first * rhs.$first $(+ self.$comp * rhs.$comp)+
+            }
+        }
+
+        impl<Q: HasRefUnit> $vector<Q>
+        where
+            Q::UnitType: LinearScaledUnit,
+        {
+            /// Returns a new instance of
+            #[doc = concat!("`", stringify!($vector), "`")]
+            /// with all components converted to `to_unit`.
+            pub fn convert(&self, to_unit: Q::UnitType) -> Self {
+                Self {
+                    $first: self.$first.convert(to_unit),
+                    $($comp: self.$comp.convert(to_unit),)+
+                }
+            }
+
+            /// Returns the length (euclidean norm) of `self`, in the unit of
+            /// its first component.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the result can not be represented as `AmountT`.
+            #[cfg(feature = "std")]
+            pub fn norm(&self) -> Q {
+                let unit = self.$first.unit();
+                let sum_of_squares = [self.$first $(, self.$comp)+]
+                    .iter()
+                    .map(|qty| amnt_to_f64(qty.equiv_amount(unit)))
+                    .fold(0_f64, |sum, amnt| amnt.mul_add(amnt, sum));
+                let res = sum_of_squares.sqrt();
+                match amnt_from_f64(res) {
+                    Some(amnt) => Q::new(amnt, unit),
+                    None => {
+                        panic!("Result not representable as AmountT: {res}")
+                    }
+                }
+            }
+        }
+
+        impl<Q: Quantity + fmt::Display> fmt::Display for $vector<Q> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "({}", self.$first)?;
+                $(write!(f, ", {}", self.$comp)?;)+
+                write!(f, ")")
+            }
+        }
+
+        impl<Q: Quantity> Add for $vector<Q>
+        where
+            Q: Add<Output = Q>,
+        {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    $first: self.$first + rhs.$first,
+                    $($comp: self.$comp + rhs.$comp,)+
+                }
+            }
+        }
+
+        impl<Q: Quantity> Sub for $vector<Q>
+        where
+            Q: Sub<Output = Q>,
+        {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self {
+                    $first: self.$first - rhs.$first,
+                    $($comp: self.$comp - rhs.$comp,)+
+                }
+            }
+        }
+
+        impl<Q: Quantity> Neg for $vector<Q>
+        where
+            Q: Neg<Output = Q>,
+        {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self {
+                    $first: -self.$first,
+                    $($comp: -self.$comp,)+
+                }
+            }
+        }
+
+        impl<Q: Quantity> Mul<AmountT> for $vector<Q>
+        where
+            Q: Mul<AmountT, Output = Q>,
+        {
+            type Output = Self;
+
+            fn mul(self, rhs: AmountT) -> Self::Output {
+                Self {
+                    $first: self.$first * rhs,
+                    $($comp: self.$comp * rhs,)+
+                }
+            }
+        }
+
+        impl<Q: Quantity> Mul<$vector<Q>> for AmountT
+        where
+            Q: Mul<AmountT, Output = Q>,
+        {
+            type Output = $vector<Q>;
+
+            fn mul(self, rhs: $vector<Q>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl<Q: Quantity> Div<AmountT> for $vector<Q>
+        where
+            Q: Div<AmountT, Output = Q>,
+        {
+            type Output = Self;
+
+            fn div(self, rhs: AmountT) -> Self::Output {
+                Self {
+                    $first: self.$first / rhs,
+                    $($comp: self.$comp / rhs,)+
+                }
+            }
+        }
+    };
+}
+
+/// Two-dimensional vector of values of quantity `Q`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Vector2<Q: Quantity> {
+    /// x component
+    pub x: Q,
+    /// y component
+    pub y: Q,
+}
+
+impl_vector!(Vector2, 2, x, y);
+
+/// Three-dimensional vector of values of quantity `Q`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Vector3<Q: Quantity> {
+    /// x component
+    pub x: Q,
+    /// y component
+    pub y: Q,
+    /// z component
+    pub z: Q,
+}
+
+impl_vector!(Vector3, 3, x, y, z);
+
+impl<Q: Quantity> Vector3<Q> {
+    /// Returns the cross product of `self` and `rhs`.
+    ///
+    /// The result has the type of the product of the components'
+    /// quantities, for example `Area` for two displacements.
+    pub fn cross<R: Quantity>(
+        self,
+        rhs: Vector3<R>,
+    ) -> Vector3<<Q as Mul<R>>::Output>
+    where
+        Q: Mul<R>,
+        <Q as Mul<R>>::Output: Quantity + Sub<Output = <Q as Mul<R>>::Output>,
+    {
+        Vector3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `Voltage`.
+
+use crate::{electric_current::ElectricCurrent, power::Power, prelude::*};
+
+#[quantity(Power / ElectricCurrent)]
+#[ref_unit(Volt, "V", NONE, "Reference unit of quantity `Voltage`")]
+#[unit(Microvolt, "µV", MICRO, 0.000001, "0.000001·V")]
+#[unit(Millivolt, "mV", MILLI, 0.001, "0.001·V")]
+#[unit(Kilovolt, "kV", KILO, 1000, "1000·V")]
+#[unit(Megavolt, "MV", MEGA, 1000000, "1000000·V")]
+/// Difference in electric potential between two points
+///
+/// Definition: Power/ElectricCurrent
+///
+/// Reference unit: Volt ('V' = 'W/A' = 'kg·m²/(s³·A)')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                  | Definition        | Equivalent in 'V'   |
+/// |--------|-----------------------|-------------------|---------------------|
+/// | µV     | Microvolt             | 0.000001·V        | 0.000001            |
+/// | mV     | Millivolt             | 0.001·V           | 0.001               |
+/// | kV     | Kilovolt              | 1000·V            | 1000                |
+/// | MV     | Megavolt              | 1000000·V         | 1000000             |
+pub struct Voltage {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        electric_current::{AMPERE, MILLIAMPERE},
+        power::{KILOWATT, MILLIWATT},
+    };
+
+    #[test]
+    fn test_power_div_current() {
+        let ap: AmountT = Amnt!(2.3);
+        let p = ap * KILOWATT;
+        let ai: AmountT = Amnt!(10.);
+        let i = ai * AMPERE;
+        let u = p / i;
+        assert_almost_eq!(u.amount(), ap / ai);
+        assert_eq!(u.unit(), KILOVOLT);
+    }
+
+    #[test]
+    fn test_voltage_mul_current() {
+        let au: AmountT = Amnt!(3.3);
+        let u = au * VOLT;
+        let ai: AmountT = Amnt!(20.);
+        let i = ai * MILLIAMPERE;
+        let p = u * i;
+        assert_almost_eq!(p.amount(), au * ai);
+        assert_eq!(p.unit(), MILLIWATT);
+        let i = p / u;
+        assert_almost_eq!(i.amount(), ai);
+        assert_eq!(i.unit(), MILLIAMPERE);
+    }
+}
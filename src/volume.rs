@@ -18,9 +18,17 @@ use crate::{area::Area, length::Length, prelude::*};
 #[unit(Milliliter, "ml", MICRO, 0.000001, "0.001·l")]
 #[unit(Centiliter, "cl", 0.00001, "0.01·l")]
 #[unit(Cubic_Inch, "in³", 0.000016387064, "in³")]
+#[unit(Imperial_Fluid_Ounce, "imp fl oz", 0.0000284130625, "imp gal/160")]
+#[unit(US_Fluid_Ounce, "US fl oz", 0.0000295735295625, "US gal/128")]
 #[unit(Deciliter, "dl", 0.0001, "0.1·l")]
+#[unit(US_Pint, "US pt", 0.000473176473, "US gal/8")]
+#[unit(Imperial_Pint, "imp pt", 0.00056826125, "imp gal/8")]
+#[unit(US_Quart, "US qt", 0.000946352946, "US gal/4")]
 #[unit(Cubic_Decimeter, "dm³", MILLI, 0.001, "dm³")]
 #[unit(Liter, "l", MILLI, 0.001, "0.001·m³")]
+#[unit(Imperial_Quart, "imp qt", 0.0011365225, "imp gal/4")]
+#[unit(US_Gallon, "US gal", 0.003785411784, "231·in³")]
+#[unit(Imperial_Gallon, "imp gal", 0.00454609, "4.54609·l")]
 #[unit(Cubic_Foot, "ft³", 0.028316846592, "ft³")]
 #[unit(Cubic_Yard, "yd³", 0.764554857984, "yd³")]
 #[unit(Cubic_Kilometer, "km³", GIGA, 1000000000, "km³")]
@@ -33,19 +41,27 @@ use crate::{area::Area, length::Length, prelude::*};
 ///
 /// Predefined units:
 ///
-/// | Symbol | Name                  | Definition        | Equivalent in 'm³'  |
-/// |--------|-----------------------|-------------------|---------------------|
-/// | mm³    | Cubic Millimeter      | mm³               | 0.000000001         |
-/// | cm³    | Cubic Centimeter      | cm³               | 0.000001            |
-/// | ml     | Milliliter            | 0.001·l           | 0.000001            |
-/// | cl     | Centiliter            | 0.01·l            | 0.00001             |
-/// | in³    | Cubic Inch            | in³               | 0.000016387064      |
-/// | dl     | Deciliter             | 0.1·l             | 0.0001              |
-/// | dm³    | Cubic Decimeter       | dm³               | 0.001               |
-/// | l      | Liter                 | 0.001·m³          | 0.001               |
-/// | ft³    | Cubic Foot            | ft³               | 0.028316846592      |
-/// | yd³    | Cubic Yard            | yd³               | 0.764554857984      |
-/// | km³    | Cubic Kilometer       | km³               | 1000000000          |
+/// | Symbol    | Name                  | Definition  | Equivalent in 'm³'  |
+/// |-----------|-----------------------|-------------|---------------------|
+/// | mm³       | Cubic Millimeter      | mm³         | 0.000000001         |
+/// | cm³       | Cubic Centimeter      | cm³         | 0.000001            |
+/// | ml        | Milliliter            | 0.001·l     | 0.000001            |
+/// | cl        | Centiliter            | 0.01·l      | 0.00001             |
+/// | in³       | Cubic Inch            | in³         | 0.000016387064      |
+/// | imp fl oz | Imperial Fluid Ounce  | imp gal/160 | 0.0000284130625     |
+/// | US fl oz  | US Fluid Ounce        | US gal/128  | 0.0000295735295625  |
+/// | dl        | Deciliter             | 0.1·l       | 0.0001              |
+/// | US pt     | US Pint               | US gal/8    | 0.000473176473      |
+/// | imp pt    | Imperial Pint         | imp gal/8   | 0.00056826125       |
+/// | US qt     | US Quart              | US gal/4    | 0.000946352946      |
+/// | dm³       | Cubic Decimeter       | dm³         | 0.001               |
+/// | l         | Liter                 | 0.001·m³    | 0.001               |
+/// | imp qt    | Imperial Quart        | imp gal/4   | 0.0011365225        |
+/// | US gal    | US Gallon             | 231·in³     | 0.003785411784      |
+/// | imp gal   | Imperial Gallon       | 4.54609·l   | 0.00454609          |
+/// | ft³       | Cubic Foot            | ft³         | 0.028316846592      |
+/// | yd³       | Cubic Yard            | yd³         | 0.764554857984      |
+/// | km³       | Cubic Kilometer       | km³         | 1000000000          |
 pub struct Volume {}
 
 #[cfg(test)]
@@ -93,4 +109,24 @@ mod tests {
         assert_almost_eq!(h.amount(), v.amount() / a.amount());
         assert_eq!(h.unit(), MILLIMETER);
     }
+
+    #[test]
+    fn test_us_and_imperial_units() {
+        let v = Amnt!(1.) * US_GALLON;
+        let w = v.convert(LITER);
+        assert_almost_eq!(w.amount(), Amnt!(3.785411784));
+        let w = v.convert(US_FLUID_OUNCE);
+        assert_almost_eq!(w.amount(), Amnt!(128.));
+        let w = v.convert(US_QUART);
+        assert_almost_eq!(w.amount(), Amnt!(4.));
+        let v = Amnt!(1.) * IMPERIAL_GALLON;
+        let w = v.convert(IMPERIAL_PINT);
+        assert_almost_eq!(w.amount(), Amnt!(8.));
+        let w = v.convert(IMPERIAL_FLUID_OUNCE);
+        assert_almost_eq!(w.amount(), Amnt!(160.));
+        let w = v.convert(US_GALLON);
+        assert_almost_eq!(w.amount(), Amnt!(1.2009499255048548));
+        let w = (Amnt!(2.) * IMPERIAL_QUART).convert(US_PINT);
+        assert_almost_eq!(w.amount(), Amnt!(4.803799702019419));
+    }
 }
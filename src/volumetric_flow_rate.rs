@@ -0,0 +1,82 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+//! Definition of derived quantity `VolumetricFlowRate`.
+
+use crate::{duration::Duration, prelude::*, volume::Volume};
+
+#[quantity(Volume / Duration)]
+#[ref_unit(
+    Cubic_Meter_per_Second,
+    "m³/s",
+    NONE,
+    "Reference unit of quantity `VolumetricFlowRate`"
+)]
+#[unit(Milliliter_per_Minute, "ml/min", 0.000000016666666667, "ml/min")]
+#[unit(Liter_per_Hour, "l/h", 0.000000277777777778, "l/h")]
+#[unit(Liter_per_Minute, "l/min", 0.000016666666666667, "l/min")]
+#[unit(US_Gallon_per_Minute, "gpm", 0.0000630901964, "US gal/min")]
+#[unit(Cubic_Meter_per_Hour, "m³/h", 0.000277777777777778, "m³/h")]
+#[unit(Cubic_Foot_per_Minute, "cfm", 0.0004719474432, "ft³/min")]
+#[unit(Liter_per_Second, "l/s", MILLI, 0.001, "l/s")]
+#[unit(Cubic_Meter_per_Minute, "m³/min", 0.016666666666666667, "m³/min")]
+/// Volume of a fluid passing per unit of time
+///
+/// Definition: Volume/Duration
+///
+/// Reference unit: Cubic Meter per Second ('m³/s')
+///
+/// Predefined units:
+///
+/// | Symbol | Name                   | Definition | Equiv. in 'm³/s'     |
+/// |--------|------------------------|------------|----------------------|
+/// | ml/min | Milliliter per Minute  | ml/min     | 0.000000016666666667 |
+/// | l/h    | Liter per Hour         | l/h        | 0.000000277777777778 |
+/// | l/min  | Liter per Minute       | l/min      | 0.000016666666666667 |
+/// | gpm    | US Gallon per Minute   | US gal/min | 0.0000630901964      |
+/// | m³/h   | Cubic Meter per Hour   | m³/h       | 0.000277777777777778 |
+/// | cfm    | Cubic Foot per Minute  | ft³/min    | 0.0004719474432      |
+/// | l/s    | Liter per Second       | l/s        | 0.001                |
+/// | m³/min | Cubic Meter per Minute | m³/min     | 0.016666666666666667 |
+pub struct VolumetricFlowRate {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_almost_eq,
+        duration::SECOND,
+        volume::{CUBIC_METER, LITER},
+    };
+
+    #[test]
+    fn test_volume_div_duration() {
+        let av: AmountT = Amnt!(12.5);
+        let v = av * LITER;
+        let at: AmountT = Amnt!(2.5);
+        let t = at * SECOND;
+        let r = v / t;
+        assert_almost_eq!(r.amount(), av / at);
+        assert_eq!(r.unit(), LITER_PER_SECOND);
+        let r = r.convert(US_GALLON_PER_MINUTE);
+        assert_almost_eq!(r.amount(), Amnt!(79.25161570744452));
+    }
+
+    #[test]
+    fn test_volumetric_flow_rate_mul_duration() {
+        let ar: AmountT = Amnt!(36.);
+        let r = ar * CUBIC_METER_PER_HOUR;
+        let at: AmountT = Amnt!(20.);
+        let t = at * SECOND;
+        let v = r * t;
+        assert_almost_eq!(v.equiv_amount(CUBIC_METER), Amnt!(0.2));
+        let t = v / r;
+        assert_almost_eq!(t.equiv_amount(SECOND), at);
+    }
+}
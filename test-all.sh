@@ -1,5 +1,5 @@
 #!/bin/bash
 TRYBUILD=overwrite cargo hack test --lib --tests --no-fail-fast --feature-powerset --optional-deps \
-  --group-features mass,length,duration,area,volume,speed,acceleration,force,energy,power,frequency,datavolume,datathroughput,temperature \
+  --group-features mass,length,duration,area,volume,speed,acceleration,force,energy,power,frequency,datavolume,datathroughput,temperature,electric_current,electric_charge,voltage,resistance,capacitance,inductance,pressure,density,mass_flow_rate,volumetric_flow_rate,angle,angular_velocity,solid_angle,luminous_intensity,luminous_flux,illuminance,irradiance,amount_of_substance,molar_mass,molar_concentration,torque,fuel_consumption,fuel_economy,level,fraction,money \
   --exclude-features doc
 cargo test --doc --features fpdec
@@ -0,0 +1,161 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(test)]
+mod converter_tests {
+    use quantities::{
+        assert_almost_eq, prelude::*, ContextEquivalence, ConversionTable,
+        Converter, Equivalence, ProportionalEquivalence,
+    };
+
+    #[quantity]
+    #[unit(Ape, "a")]
+    #[unit(Bee, "b")]
+    #[unit(Cow, "c")]
+    #[unit(Dog, "d")]
+    #[unit(Eel, "e")]
+    struct Zoo {}
+
+    #[quantity]
+    #[ref_unit(Apple, "apl")]
+    #[unit(Crate, "crt", 20, "20·apl")]
+    struct Fruit {}
+
+    #[quantity]
+    #[ref_unit(Cent, "ct")]
+    #[unit(Euro, "EUR", 100, "100·ct")]
+    struct Cost {}
+
+    // price in cents per apple
+    fn at_price(price: AmountT) -> ContextEquivalence<Fruit, Cost, AmountT> {
+        ContextEquivalence::new(
+            price,
+            |price, fruit| Some(fruit.equiv_amount(APPLE) * *price * CENT),
+            |price, cost| {
+                (*price != Amnt!(0))
+                    .then(|| cost.equiv_amount(CENT) / *price * APPLE)
+            },
+        )
+    }
+
+    // d = (a * 2 + 1), a = b * 4, c = d * 0.5 - 3, e is not connected
+    const ZOO_CONVERTER: ConversionTable<Zoo, 3> = ConversionTable {
+        mappings: [
+            (APE, DOG, Amnt!(2), Amnt!(1)),
+            (BEE, APE, Amnt!(4), Amnt!(0)),
+            (DOG, COW, Amnt!(0.5), Amnt!(-3)),
+        ],
+    };
+
+    fn convert_all<C: Converter<Zoo>>(conv: C, qty: &Zoo) -> Vec<Zoo> {
+        Zoo::iter_units()
+            .filter_map(|unit| conv.convert(qty, unit))
+            .collect()
+    }
+
+    #[test]
+    fn test_direct_conversion() {
+        let a = Amnt!(3) * APE;
+        let d = ZOO_CONVERTER.convert(&a, DOG).unwrap();
+        assert_eq!(d.unit(), DOG);
+        assert_almost_eq!(d.amount(), Amnt!(7));
+    }
+
+    #[test]
+    fn test_inverse_conversion() {
+        let d = Amnt!(7) * DOG;
+        let a = ZOO_CONVERTER.convert(&d, APE).unwrap();
+        assert_eq!(a.unit(), APE);
+        assert_almost_eq!(a.amount(), Amnt!(3));
+        let a = Amnt!(10) * APE;
+        let b = ZOO_CONVERTER.convert(&a, BEE).unwrap();
+        assert_almost_eq!(b.amount(), Amnt!(2.5));
+    }
+
+    #[test]
+    fn test_chained_conversion() {
+        let b = Amnt!(0.5) * BEE;
+        // b -> a: 2, a -> d: 5, d -> c: -0.5
+        let c = ZOO_CONVERTER.convert(&b, COW).unwrap();
+        assert_eq!(c.unit(), COW);
+        assert_almost_eq!(c.amount(), Amnt!(-0.5));
+        let b = ZOO_CONVERTER.convert(&c, BEE).unwrap();
+        assert_almost_eq!(b.amount(), Amnt!(0.5));
+        assert_eq!(
+            ZOO_CONVERTER.conversion(BEE, COW),
+            Some((Amnt!(4), Amnt!(-2.5)))
+        );
+    }
+
+    #[test]
+    fn test_no_conversion() {
+        let a = Amnt!(3) * APE;
+        assert!(ZOO_CONVERTER.convert(&a, EEL).is_none());
+        let e = Amnt!(3) * EEL;
+        assert_eq!(ZOO_CONVERTER.convert(&e, EEL), Some(e));
+        assert!(ZOO_CONVERTER.convert(&e, APE).is_none());
+    }
+
+    #[test]
+    fn test_zero_factor_not_inverted() {
+        // every amount of ape maps to 5 bee, so bee can't be mapped to ape
+        const CONVERTER: ConversionTable<Zoo, 2> = ConversionTable {
+            mappings: [
+                (APE, BEE, Amnt!(0), Amnt!(5)),
+                (COW, BEE, Amnt!(2), Amnt!(0)),
+            ],
+        };
+        let a = Amnt!(3) * APE;
+        let c = CONVERTER.convert(&a, COW).unwrap();
+        assert_almost_eq!(c.amount(), Amnt!(2.5));
+        let b = Amnt!(3) * BEE;
+        assert!(CONVERTER.convert(&b, APE).is_none());
+        let c = Amnt!(3) * COW;
+        assert!(CONVERTER.convert(&c, APE).is_none());
+        assert_eq!(CONVERTER.conversion(BEE, APE), None);
+    }
+
+    #[test]
+    fn test_converter_by_ref() {
+        let conv = &ZOO_CONVERTER;
+        let a = Amnt!(3) * APE;
+        let all = convert_all(conv, &a);
+        assert_eq!(all.len(), 4);
+        // converter is still usable
+        let all = convert_all(&ZOO_CONVERTER, &a);
+        assert_eq!(all.len(), 4);
+    }
+
+    #[test]
+    fn test_proportional_equivalence() {
+        let eqv: ProportionalEquivalence<Fruit, Cost> =
+            ProportionalEquivalence::new(Amnt!(25));
+        let f = Amnt!(2) * CRATE;
+        let c = eqv.convert(&f, EURO).unwrap();
+        assert_almost_eq!(c.amount(), Amnt!(10));
+        assert_eq!(c.unit(), EURO);
+        let f = eqv.convert_back(&c, APPLE).unwrap();
+        assert_almost_eq!(f.amount(), Amnt!(40));
+    }
+
+    #[test]
+    fn test_context_equivalence() {
+        let eqv = at_price(Amnt!(30));
+        assert_eq!(*eqv.context(), Amnt!(30));
+        let f = Amnt!(5) * CRATE;
+        let c = eqv.convert(&f, EURO).unwrap();
+        assert_almost_eq!(c.amount(), Amnt!(30));
+        assert_eq!(c.unit(), EURO);
+        let c = Amnt!(1.5) * EURO;
+        let f = eqv.convert_back(&c, APPLE).unwrap();
+        assert_almost_eq!(f.amount(), Amnt!(5));
+        let eqv = at_price(Amnt!(0));
+        assert!(eqv.convert_back(&c, APPLE).is_none());
+    }
+}
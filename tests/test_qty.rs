@@ -428,6 +428,27 @@ mod quantity_with_ref_unit_tests {
         assert_almost_eq!(qty1.amount(), amnt1 / amnt2);
         assert_eq!(qty1.unit(), unit1);
     }
+
+    #[test]
+    fn test_sum() {
+        let qties = vec![
+            Amnt!(2.5) * FooUnit::B,
+            Amnt!(1) * FooUnit::A,
+            Amnt!(20) * FooUnit::C,
+        ];
+        let res: Foo = qties.iter().sum();
+        assert_eq!(res.unit(), FooUnit::B);
+        assert_almost_eq!(res.amount(), Amnt!(5.5));
+        let res: Foo = qties.into_iter().sum();
+        assert_almost_eq!(res.amount(), Amnt!(5.5));
+    }
+
+    #[test]
+    fn test_sum_empty() {
+        let res: Foo = Vec::<Foo>::new().into_iter().sum();
+        assert_eq!(res, Foo::default());
+        assert_eq!(res.unit(), FooUnit::A);
+    }
 }
 
 #[cfg(test)]
@@ -695,6 +716,26 @@ mod quantity_without_ref_unit_tests {
         assert_almost_eq!(qty1.amount(), amnt1 / amnt2);
         assert_eq!(qty1.unit(), unit1);
     }
+
+    #[test]
+    fn test_try_sum() {
+        let qties = [Amnt!(17.4) * FooUnit::A, Amnt!(0.6) * FooUnit::A];
+        let res = Foo::try_sum(qties).unwrap();
+        assert_eq!(res.unit(), FooUnit::A);
+        assert_almost_eq!(res.amount(), Amnt!(18));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_try_sum_diff_unit() {
+        let qties = [Amnt!(17.4) * FooUnit::A, Amnt!(0.6) * FooUnit::B];
+        let _res = Foo::try_sum(qties);
+    }
+
+    #[test]
+    fn test_try_sum_empty() {
+        assert!(Foo::try_sum(Vec::<Foo>::new()).is_none());
+    }
 }
 
 #[cfg(test)]
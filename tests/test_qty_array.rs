@@ -0,0 +1,143 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(all(test, feature = "ndarray"))]
+mod qty_array_tests {
+    use ndarray::{array, Array1, Axis};
+    use quantities::{
+        prelude::*,
+        qty_array::{QtyArray, QtyArrayBase},
+        qty_vec::QtyVec,
+    };
+
+    #[quantity]
+    #[ref_unit(Flop, "f")]
+    #[unit(Kiloflop, "kf", 1000., "1000·f")]
+    #[unit(Centiflop, "cf", 0.01, "0.01·f")]
+    struct Foo {}
+
+    fn sample() -> QtyArray<Foo, ndarray::Ix2> {
+        QtyArrayBase::new(
+            array![
+                [Amnt!(1), Amnt!(2), Amnt!(3)],
+                [Amnt!(4), Amnt!(5), Amnt!(6)]
+            ],
+            KILOFLOP,
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let a = sample();
+        assert_eq!(a.unit(), KILOFLOP);
+        assert_eq!(a.shape(), [2, 3]);
+        assert_eq!(a.len(), 6);
+        assert!(!a.is_empty());
+        assert_eq!(a.get([1, 2]), Some(Amnt!(6) * KILOFLOP));
+        assert_eq!(a.get([2, 0]), None);
+        assert_eq!(a.iter().nth(3), Some(Amnt!(4) * KILOFLOP));
+        assert_eq!(a.view(), a);
+        assert_eq!(a.view().to_owned(), a);
+        assert_eq!(a.clone().into_amounts(), a.amounts());
+    }
+
+    #[test]
+    fn test_from_qty_vec() {
+        let v: QtyVec<Foo> = [Amnt!(1) * FLOP, Amnt!(200) * CENTIFLOP]
+            .into_iter()
+            .collect();
+        let a = QtyArray::from(v);
+        assert_eq!(a.unit(), FLOP);
+        assert_eq!(a.amounts(), array![Amnt!(1), Amnt!(2)]);
+    }
+
+    #[test]
+    fn test_convert() {
+        let mut a = sample();
+        let b = a.convert(FLOP);
+        assert_eq!(b.unit(), FLOP);
+        assert_eq!(
+            b.amounts(),
+            array![
+                [Amnt!(1000), Amnt!(2000), Amnt!(3000)],
+                [Amnt!(4000), Amnt!(5000), Amnt!(6000)]
+            ]
+        );
+        assert_eq!(a.convert(KILOFLOP), a);
+        a.convert_assign(FLOP);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let a = sample();
+        let b = &a * Amnt!(2);
+        assert_eq!(b.get([1, 0]), Some(Amnt!(8) * KILOFLOP));
+        assert_eq!(&b / Amnt!(2), a);
+        assert_eq!(b.clone() / Amnt!(2), a);
+        assert_eq!(a.clone() * Amnt!(2), b);
+        let c = &a + Amnt!(500) * FLOP;
+        assert_eq!(c.unit(), KILOFLOP);
+        assert_eq!(c.get([0, 0]), Some(Amnt!(1.5) * KILOFLOP));
+        assert_eq!(&c - Amnt!(0.5) * KILOFLOP, a);
+    }
+
+    #[test]
+    fn test_array_ops() {
+        let a = sample();
+        let b = a.convert(FLOP);
+        assert_eq!(&a + &b, &a * Amnt!(2));
+        assert_eq!(&b - &a, &b * Amnt!(0));
+        // broadcasting a row
+        let r = QtyArrayBase::new(
+            array![Amnt!(100), Amnt!(200), Amnt!(300)],
+            FLOP,
+        );
+        let c = &b + &r;
+        assert_eq!(c.unit(), FLOP);
+        assert_eq!(
+            c.amounts(),
+            array![
+                [Amnt!(1100), Amnt!(2200), Amnt!(3300)],
+                [Amnt!(4100), Amnt!(5200), Amnt!(6300)]
+            ]
+        );
+        let d = &r - &a.view();
+        assert_eq!(d.shape(), [2, 3]);
+        assert_eq!(d.get([1, 2]), Some(Amnt!(-5700) * FLOP));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_array_ops_incompatible_shapes() {
+        let a = sample();
+        let b = QtyArrayBase::new(array![Amnt!(1), Amnt!(2)], KILOFLOP);
+        let _ = &a + &b;
+    }
+
+    #[test]
+    fn test_reductions() {
+        let a = sample();
+        assert_eq!(a.sum(), Amnt!(21) * KILOFLOP);
+        assert_eq!(a.mean(), Some(Amnt!(3.5) * KILOFLOP));
+        assert_eq!(a.min(), Some(Amnt!(1) * KILOFLOP));
+        assert_eq!(a.max(), Some(Amnt!(6) * KILOFLOP));
+        let s = a.sum_axis(Axis(0));
+        assert_eq!(s.unit(), KILOFLOP);
+        assert_eq!(s.amounts(), array![Amnt!(5), Amnt!(7), Amnt!(9)]);
+        let m = a.mean_axis(Axis(1)).unwrap();
+        assert_eq!(m.amounts(), array![Amnt!(2), Amnt!(5)]);
+        let e = QtyArrayBase::<Foo, _, _>::new(Array1::from_vec(vec![]), FLOP);
+        assert_eq!(e.sum(), Amnt!(0) * FLOP);
+        assert!(e.mean().is_none());
+        assert!(e.min().is_none());
+        assert!(e.max().is_none());
+        assert!(e.mean_axis(Axis(0)).is_none());
+    }
+}
@@ -0,0 +1,168 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(test)]
+mod qty_vec_tests {
+    use quantities::{
+        prelude::*,
+        qty_vec::{QtySlice, QtyVec},
+    };
+
+    #[quantity]
+    #[ref_unit(Flop, "f")]
+    #[unit(Kiloflop, "kf", 1000., "1000·f")]
+    #[unit(Centiflop, "cf", 0.01, "0.01·f")]
+    struct Foo {}
+
+    fn amounts(vals: &[i16]) -> Vec<AmountT> {
+        vals.iter().map(|&v| AmountT::from(v)).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let mut v = QtyVec::<Foo>::new(KILOFLOP);
+        assert!(v.is_empty());
+        assert_eq!(v.unit(), KILOFLOP);
+        v.push(Amnt!(2) * KILOFLOP);
+        v.push(Amnt!(500) * FLOP);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v.amounts(), [Amnt!(2), Amnt!(0.5)]);
+        assert_eq!(v.get(1), Some(Amnt!(0.5) * KILOFLOP));
+        assert_eq!(v.get(2), None);
+        let v = QtyVec::<Foo>::from_amounts(amounts(&[1, 2, 3]), CENTIFLOP);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.get(2), Some(Amnt!(3) * CENTIFLOP));
+        assert_eq!(v.into_amounts(), amounts(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_from_into_vec() {
+        let qties = vec![
+            Amnt!(2) * KILOFLOP,
+            Amnt!(500) * FLOP,
+            Amnt!(100000) * CENTIFLOP,
+        ];
+        let v = QtyVec::from(qties.clone());
+        assert_eq!(v.unit(), KILOFLOP);
+        assert_eq!(v.len(), 3);
+        let w: Vec<Foo> = v.into();
+        assert_eq!(
+            w,
+            vec![
+                Amnt!(2) * KILOFLOP,
+                Amnt!(0.5) * KILOFLOP,
+                Amnt!(1) * KILOFLOP
+            ]
+        );
+        let v: QtyVec<Foo> = qties.iter().copied().collect();
+        assert_eq!(v, QtyVec::from(qties.as_slice()));
+        let v = QtyVec::<Foo>::from(vec![]);
+        assert!(v.is_empty());
+        assert_eq!(v.unit(), FLOP);
+        let mut v = QtyVec::<Foo>::new(FLOP);
+        v.extend(qties);
+        assert_eq!(v.amounts(), amounts(&[2000, 500, 1000]));
+    }
+
+    #[test]
+    fn test_slice() {
+        let v = QtyVec::<Foo>::from_amounts(amounts(&[1, 2, 3, 4]), FLOP);
+        let s = v.as_slice();
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.unit(), FLOP);
+        assert_eq!(s.amounts(), v.amounts());
+        let t = v.slice(1..3).unwrap();
+        assert_eq!(t.amounts(), amounts(&[2, 3]));
+        assert_eq!(t.get(0), Some(Amnt!(2) * FLOP));
+        assert_eq!(t.slice(1..).unwrap().to_vec(), vec![Amnt!(3) * FLOP]);
+        assert!(v.slice(3..5).is_none());
+        assert!(t.slice(..).unwrap().slice(2..2).unwrap().is_empty());
+        assert_eq!(t.to_qty_vec().iter().count(), 2);
+        assert_eq!(QtySlice::from(&v), s);
+        let a = amounts(&[5, 6]);
+        let s = QtySlice::<Foo>::new(&a, KILOFLOP);
+        assert_eq!(
+            s.iter().collect::<Vec<_>>(),
+            vec![Amnt!(5) * KILOFLOP, Amnt!(6) * KILOFLOP]
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let mut v = QtyVec::<Foo>::from_amounts(amounts(&[1, 2, 3]), KILOFLOP);
+        let w = v.convert(FLOP);
+        assert_eq!(w.unit(), FLOP);
+        assert_eq!(w.amounts(), amounts(&[1000, 2000, 3000]));
+        let w = v.slice(1..).unwrap().convert(FLOP);
+        assert_eq!(w.amounts(), amounts(&[2000, 3000]));
+        v.convert_assign(CENTIFLOP);
+        assert_eq!(v.unit(), CENTIFLOP);
+        assert_eq!(v.amounts(), [Amnt!(100000), Amnt!(200000), Amnt!(300000)]);
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let v = QtyVec::<Foo>::from_amounts(amounts(&[1, 2, 3]), KILOFLOP);
+        let w = v.clone() * Amnt!(2);
+        assert_eq!(w.amounts(), amounts(&[2, 4, 6]));
+        assert_eq!(Amnt!(2) * v.clone(), w);
+        assert_eq!(w.clone() / Amnt!(2), v);
+        assert_eq!(w.as_slice() / Amnt!(2), v);
+        assert_eq!(v.as_slice() * Amnt!(2), w);
+        assert_eq!((-v.clone()).amounts(), amounts(&[-1, -2, -3]));
+        let mut u = v.clone();
+        u *= Amnt!(3);
+        u /= Amnt!(3);
+        assert_eq!(u, v);
+    }
+
+    #[test]
+    fn test_qty_ops() {
+        let v = QtyVec::<Foo>::from_amounts(amounts(&[1, 2, 3]), KILOFLOP);
+        let w = v.clone() + Amnt!(500) * FLOP;
+        assert_eq!(w.unit(), KILOFLOP);
+        assert_eq!(w.amounts(), [Amnt!(1.5), Amnt!(2.5), Amnt!(3.5)]);
+        assert_eq!(w - Amnt!(0.5) * KILOFLOP, v);
+        assert_eq!(v.as_slice() + Amnt!(0) * FLOP, v);
+        assert_eq!(
+            v.as_slice() - Amnt!(1) * KILOFLOP,
+            v.clone() - v.get(0).unwrap()
+        );
+        let mut u = v.clone();
+        u += Amnt!(1000) * FLOP;
+        u -= Amnt!(1) * KILOFLOP;
+        assert_eq!(u, v);
+    }
+
+    #[test]
+    fn test_array_ops() {
+        let v = QtyVec::<Foo>::from_amounts(amounts(&[1, 2, 3]), KILOFLOP);
+        let w = QtyVec::<Foo>::from_amounts(amounts(&[100, 200, 300]), FLOP);
+        let s = v.clone() + &w;
+        assert_eq!(s.unit(), KILOFLOP);
+        assert_eq!(s.amounts(), [Amnt!(1.1), Amnt!(2.2), Amnt!(3.3)]);
+        let d = w.as_slice() - &v;
+        assert_eq!(d.unit(), FLOP);
+        assert_eq!(d.amounts(), amounts(&[-900, -1800, -2700]));
+        assert_eq!(v.clone() - v.as_slice(), v.clone() * Amnt!(0));
+        assert_eq!(v.as_slice() + v.as_slice(), v.clone() * Amnt!(2));
+        let mut u = v.clone();
+        u += &v;
+        u -= v.as_slice();
+        assert_eq!(u, v);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_array_ops_diff_len() {
+        let v = QtyVec::<Foo>::from_amounts(amounts(&[1, 2, 3]), KILOFLOP);
+        let w = QtyVec::<Foo>::from_amounts(amounts(&[1, 2]), KILOFLOP);
+        let _ = v + &w;
+    }
+}
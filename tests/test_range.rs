@@ -0,0 +1,274 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(test)]
+mod range_tests {
+    use quantities::{
+        prelude::*,
+        range::{ParseRangeError, QtyRange, Tolerance},
+    };
+
+    #[quantity]
+    #[ref_unit(Flop, "f")]
+    #[unit(Kiloflop, "kf", 1000., "1000·f")]
+    #[unit(Centiflop, "cf", 0.01, "0.01·f")]
+    struct Foo {}
+
+    #[test]
+    fn test_new() {
+        let r = QtyRange::new(Amnt!(10) * FLOP, Amnt!(1) * KILOFLOP);
+        assert_eq!(r.start(), Amnt!(10) * FLOP);
+        assert_eq!(r.end(), Amnt!(1) * KILOFLOP);
+        assert_eq!(r.width(), Amnt!(990) * FLOP);
+        let r = QtyRange::new(Amnt!(10) * FLOP, Amnt!(1000) * CENTIFLOP);
+        assert_eq!(r.width(), Amnt!(0) * FLOP);
+        let r =
+            QtyRange::from_nominal(Amnt!(10) * FLOP, Amnt!(50) * CENTIFLOP);
+        assert_eq!(r.start(), Amnt!(9.5) * FLOP);
+        assert_eq!(r.end(), Amnt!(10.5) * FLOP);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_start_gt_end() {
+        let _ = QtyRange::new(Amnt!(1) * KILOFLOP, Amnt!(999) * FLOP);
+    }
+
+    #[test]
+    fn test_contains() {
+        let r = QtyRange::new(Amnt!(10) * FLOP, Amnt!(1) * KILOFLOP);
+        assert!(r.contains(&(Amnt!(10) * FLOP)));
+        assert!(r.contains(&(Amnt!(1000) * CENTIFLOP)));
+        assert!(r.contains(&(Amnt!(0.5) * KILOFLOP)));
+        assert!(r.contains(&(Amnt!(1000) * FLOP)));
+        assert!(!r.contains(&(Amnt!(999) * CENTIFLOP)));
+        assert!(!r.contains(&(Amnt!(1.001) * KILOFLOP)));
+    }
+
+    #[test]
+    fn test_intersection_union() {
+        let r = QtyRange::new(Amnt!(10) * FLOP, Amnt!(1) * KILOFLOP);
+        let s = QtyRange::new(Amnt!(500) * FLOP, Amnt!(2) * KILOFLOP);
+        let t = QtyRange::new(Amnt!(3) * KILOFLOP, Amnt!(4) * KILOFLOP);
+        assert_eq!(
+            r.intersection(&s),
+            Some(QtyRange::new(Amnt!(500) * FLOP, Amnt!(1) * KILOFLOP))
+        );
+        assert_eq!(r.intersection(&s), s.intersection(&r));
+        assert_eq!(r.intersection(&t), None);
+        assert_eq!(
+            r.union(&s),
+            Some(QtyRange::new(Amnt!(10) * FLOP, Amnt!(2) * KILOFLOP))
+        );
+        assert_eq!(r.union(&s), s.union(&r));
+        assert_eq!(r.union(&t), None);
+        let u = QtyRange::new(Amnt!(100000) * CENTIFLOP, Amnt!(3) * KILOFLOP);
+        assert_eq!(
+            r.intersection(&u),
+            Some(QtyRange::new(
+                Amnt!(100000) * CENTIFLOP,
+                Amnt!(1) * KILOFLOP
+            ))
+        );
+        assert_eq!(
+            r.union(&u),
+            Some(QtyRange::new(Amnt!(10) * FLOP, Amnt!(3) * KILOFLOP))
+        );
+    }
+
+    #[test]
+    fn test_clamp() {
+        let r = QtyRange::new(Amnt!(10) * FLOP, Amnt!(1) * KILOFLOP);
+        assert_eq!(r.clamp(Amnt!(5) * FLOP), Amnt!(10) * FLOP);
+        assert_eq!(r.clamp(Amnt!(5) * CENTIFLOP), Amnt!(1000) * CENTIFLOP);
+        assert_eq!(r.clamp(Amnt!(2000) * FLOP), Amnt!(1000) * FLOP);
+        assert_eq!(r.clamp(Amnt!(20) * FLOP), Amnt!(20) * FLOP);
+    }
+
+    #[test]
+    fn test_steps() {
+        let r = QtyRange::new(Amnt!(1) * KILOFLOP, Amnt!(2) * KILOFLOP);
+        let steps: Vec<Foo> = r.steps(Amnt!(250) * FLOP).collect();
+        assert_eq!(
+            steps,
+            vec![
+                Amnt!(1) * KILOFLOP,
+                Amnt!(1.25) * KILOFLOP,
+                Amnt!(1.5) * KILOFLOP,
+                Amnt!(1.75) * KILOFLOP,
+                Amnt!(2) * KILOFLOP,
+            ]
+        );
+        let steps: Vec<Foo> = r.steps(Amnt!(0.3) * KILOFLOP).collect();
+        assert_eq!(steps.len(), 4);
+        assert_eq!(steps[3].unit(), KILOFLOP);
+        let r = QtyRange::new(Amnt!(1) * KILOFLOP, Amnt!(1000) * FLOP);
+        assert_eq!(r.steps(Amnt!(1) * FLOP).count(), 1);
+        let r = QtyRange::new(Amnt!(0) * FLOP, Amnt!(20) * KILOFLOP);
+        let mut steps = r.steps(Amnt!(1) * FLOP);
+        assert_eq!(steps.nth(20000), Some(Amnt!(20000) * FLOP));
+        assert_eq!(steps.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_steps_non_positive() {
+        let r = QtyRange::new(Amnt!(1) * KILOFLOP, Amnt!(2) * KILOFLOP);
+        let _ = r.steps(Amnt!(0) * FLOP);
+    }
+
+    #[test]
+    fn test_range_display_parse() {
+        let r = QtyRange::new(Amnt!(-10) * FLOP, Amnt!(1.5) * KILOFLOP);
+        assert_eq!(r.to_string(), "-10 f – 1.5 kf");
+        assert_eq!(format!("{r:.1}"), "-10.0 f – 1.5 kf");
+        assert_eq!(r.to_string().parse::<QtyRange<Foo>>(), Ok(r));
+        assert_eq!(
+            "0 cf–40 f".parse::<QtyRange<Foo>>(),
+            Ok(QtyRange::new(Amnt!(0) * CENTIFLOP, Amnt!(40) * FLOP))
+        );
+        assert_eq!(
+            "10 f – 1 f".parse::<QtyRange<Foo>>(),
+            Err(ParseRangeError)
+        );
+        assert_eq!(
+            "10 f - 20 f".parse::<QtyRange<Foo>>(),
+            Ok(QtyRange::new(Amnt!(10) * FLOP, Amnt!(20) * FLOP))
+        );
+        assert_eq!(
+            "-10 f - -5 cf".parse::<QtyRange<Foo>>(),
+            Ok(QtyRange::new(Amnt!(-10) * FLOP, Amnt!(-5) * CENTIFLOP))
+        );
+        assert_eq!(
+            "-10–20 kf".parse::<QtyRange<Foo>>(),
+            Ok(QtyRange::new(Amnt!(-10) * KILOFLOP, Amnt!(20) * KILOFLOP))
+        );
+        assert_eq!(
+            "10 - 20 f".parse::<QtyRange<Foo>>(),
+            Ok(QtyRange::new(Amnt!(10) * FLOP, Amnt!(20) * FLOP))
+        );
+        assert_eq!("10 – 20".parse::<QtyRange<Foo>>(), Err(ParseRangeError));
+        assert_eq!("10 f 20 f".parse::<QtyRange<Foo>>(), Err(ParseRangeError));
+        assert_eq!(
+            "10 f – 20 m".parse::<QtyRange<Foo>>(),
+            Err(ParseRangeError)
+        );
+        assert_eq!(
+            "a f – 20 f".parse::<QtyRange<Foo>>(),
+            Err(ParseRangeError)
+        );
+    }
+
+    #[cfg(feature = "temperature")]
+    #[test]
+    fn test_temperature_range() {
+        use quantities::temperature::{
+            Temperature, DEGREE_CELSIUS, DEGREE_FAHRENHEIT,
+        };
+
+        let r = QtyRange::new(
+            Amnt!(0) * DEGREE_CELSIUS,
+            Amnt!(40) * DEGREE_CELSIUS,
+        );
+        assert_eq!(r.to_string(), "0 °C – 40 °C");
+        assert_eq!("0 °C – 40 °C".parse::<QtyRange<Temperature>>(), Ok(r));
+        assert_eq!("0–40 °C".parse::<QtyRange<Temperature>>(), Ok(r));
+        assert_eq!("0 - 40 °C".parse::<QtyRange<Temperature>>(), Ok(r));
+        assert_eq!(
+            "0 °C – 40 °F".parse::<QtyRange<Temperature>>(),
+            Err(ParseRangeError)
+        );
+        assert_eq!(r.width(), Amnt!(40) * DEGREE_CELSIUS);
+        assert!(r.contains(&(Amnt!(25) * DEGREE_CELSIUS)));
+        assert!(!r.contains(&(Amnt!(-0.5) * DEGREE_CELSIUS)));
+        assert!(!r.contains(&(Amnt!(50) * DEGREE_FAHRENHEIT)));
+        assert_eq!(
+            r.clamp(Amnt!(-5) * DEGREE_CELSIUS),
+            Amnt!(0) * DEGREE_CELSIUS
+        );
+        assert_eq!(r.steps(Amnt!(10) * DEGREE_CELSIUS).count(), 5);
+        let s = QtyRange::new(
+            Amnt!(20) * DEGREE_CELSIUS,
+            Amnt!(60) * DEGREE_CELSIUS,
+        );
+        assert_eq!(
+            r.intersection(&s),
+            Some(QtyRange::new(
+                Amnt!(20) * DEGREE_CELSIUS,
+                Amnt!(40) * DEGREE_CELSIUS
+            ))
+        );
+        let f = QtyRange::new(
+            Amnt!(32) * DEGREE_FAHRENHEIT,
+            Amnt!(104) * DEGREE_FAHRENHEIT,
+        );
+        assert_eq!(r.intersection(&f), None);
+        assert_eq!(r.union(&f), None);
+        let t = Tolerance::new(
+            Amnt!(20) * DEGREE_CELSIUS,
+            Amnt!(0.5) * DEGREE_CELSIUS,
+        );
+        assert_eq!(t.max(), Amnt!(20.5) * DEGREE_CELSIUS);
+        assert_eq!("20 °C ± 0.5 °C".parse::<Tolerance<Temperature>>(), Ok(t));
+    }
+
+    #[cfg(feature = "temperature")]
+    #[test]
+    #[should_panic]
+    fn test_temperature_range_different_units() {
+        use quantities::temperature::{DEGREE_CELSIUS, DEGREE_FAHRENHEIT};
+
+        let _ = QtyRange::new(
+            Amnt!(0) * DEGREE_CELSIUS,
+            Amnt!(104) * DEGREE_FAHRENHEIT,
+        );
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let t = Tolerance::new(Amnt!(10) * FLOP, Amnt!(10) * CENTIFLOP);
+        assert_eq!(t.nominal(), Amnt!(10) * FLOP);
+        assert_eq!(t.delta(), Amnt!(10) * CENTIFLOP);
+        assert_eq!(t.min(), Amnt!(9.9) * FLOP);
+        assert_eq!(t.max(), Amnt!(10.1) * FLOP);
+        assert!(t.contains(&(Amnt!(1005) * CENTIFLOP)));
+        assert!(!t.contains(&(Amnt!(0.0102) * KILOFLOP)));
+        assert_eq!(
+            QtyRange::from(t),
+            QtyRange::from_nominal(t.nominal(), t.delta())
+        );
+        assert_eq!(t.range(), QtyRange::from(t));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tolerance_negative_delta() {
+        let _ = Tolerance::new(Amnt!(10) * FLOP, Amnt!(-1) * FLOP);
+    }
+
+    #[test]
+    fn test_tolerance_display_parse() {
+        let t = Tolerance::new(Amnt!(10) * FLOP, Amnt!(0.1) * FLOP);
+        assert_eq!(t.to_string(), "10 f ± 0.1 f");
+        assert_eq!(t.to_string().parse::<Tolerance<Foo>>(), Ok(t));
+        assert_eq!(
+            "2 kf±5 cf".parse::<Tolerance<Foo>>(),
+            Ok(Tolerance::new(Amnt!(2) * KILOFLOP, Amnt!(5) * CENTIFLOP))
+        );
+        assert_eq!(
+            "10 f ± -1 f".parse::<Tolerance<Foo>>(),
+            Err(ParseRangeError)
+        );
+        assert_eq!(
+            "10 f +- 1 f".parse::<Tolerance<Foo>>(),
+            Err(ParseRangeError)
+        );
+        assert_eq!("10 ± 1 f".parse::<Tolerance<Foo>>(), Err(ParseRangeError));
+    }
+}
@@ -86,6 +86,49 @@ mod rate_tests {
         assert_almost_eq!(b.amount, Amnt!(30000));
     }
 
+    // operators are applied to references deliberately
+    #[allow(clippy::op_ref)]
+    #[test]
+    fn test_rate_ops_by_ref() {
+        let r =
+            Rate::from_qty_vals(Amnt!(329.4) * FLOP, Amnt!(100) * MILLIEMIL);
+        let b = Amnt!(7.5) * EMIL;
+        let f = &b * &r;
+        assert_eq!(f, r * b);
+        assert_eq!(r * &b, f);
+        assert_eq!(&r * &b, f);
+        let b = &f / &r;
+        assert_eq!(b, f / r);
+        assert_eq!(b.unit, r.per_unit());
+    }
+
+    #[test]
+    fn test_rate_apply() {
+        let r = Rate::from_qty_vals(Amnt!(2) * FLOP, Amnt!(1) * EMIL);
+        let qties = [Amnt!(1) * EMIL, Amnt!(500) * MILLIEMIL];
+        let res: Vec<Foo> = r.apply(&qties).collect();
+        assert_eq!(res.len(), 2);
+        assert_almost_eq!(res[0].amount, Amnt!(2));
+        assert_almost_eq!(res[1].amount, Amnt!(1));
+        let res: Vec<Foo> =
+            r.apply(qties.iter().map(|b| *b * Amnt!(2))).collect();
+        assert_almost_eq!(res[1].amount, Amnt!(2));
+        assert_eq!(r.apply(Vec::<Bar>::new()).count(), 0);
+    }
+
+    #[test]
+    fn test_qty_per() {
+        let r = (Amnt!(329.4) * FLOP).per(Amnt!(100) * MILLIEMIL);
+        assert_eq!(r.term_amount(), Amnt!(329.4));
+        assert_eq!(r.term_unit(), FLOP);
+        assert_eq!(r.per_unit_multiple(), Amnt!(100));
+        assert_eq!(r.per_unit(), MILLIEMIL);
+        let r = Amnt!(3).per(Amnt!(1) * KILOEMIL);
+        assert_eq!(r.term_unit(), ONE);
+        let a = r * (Amnt!(2500) * EMIL);
+        assert_almost_eq!(a, Amnt!(7.5));
+    }
+
     #[test]
     fn test_rate_to_string() {
         let r =
@@ -0,0 +1,129 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(test)]
+mod statistics_tests {
+    use quantities::{assert_almost_eq, prelude::*, statistics::*};
+
+    #[quantity]
+    #[ref_unit(Flop, "f")]
+    #[unit(Kiloflop, "kf", 1000., "1000·f")]
+    #[unit(Centiflop, "cf", 0.01, "0.01·f")]
+    struct Foo {}
+
+    #[quantity(Foo * Foo)]
+    #[ref_unit(Square_Flop, "f²", "1·f²")]
+    #[unit(Square_Kiloflop, "kf²", 1000000., "1000000·f²")]
+    struct FooSquared {}
+
+    fn sample() -> [Foo; 8] {
+        [
+            Amnt!(2) * KILOFLOP,
+            Amnt!(4000) * FLOP,
+            Amnt!(4) * KILOFLOP,
+            Amnt!(400000) * CENTIFLOP,
+            Amnt!(5) * KILOFLOP,
+            Amnt!(5000) * FLOP,
+            Amnt!(7) * KILOFLOP,
+            Amnt!(9) * KILOFLOP,
+        ]
+    }
+
+    #[test]
+    fn test_mean() {
+        let m = mean(sample()).unwrap();
+        assert_eq!(m.unit(), KILOFLOP);
+        assert_almost_eq!(m.amount(), Amnt!(5));
+        let m = mean(sample().iter().copied().rev()).unwrap();
+        assert_eq!(m.unit(), KILOFLOP);
+        assert_almost_eq!(m.amount(), Amnt!(5));
+        assert!(mean::<Foo, _>([]).is_none());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let qties = sample();
+        assert_eq!(min(qties), Some(Amnt!(2) * KILOFLOP));
+        assert_eq!(max(qties), Some(Amnt!(9) * KILOFLOP));
+        let qties = [
+            Amnt!(300) * CENTIFLOP,
+            Amnt!(3) * FLOP,
+            Amnt!(0.003) * KILOFLOP,
+        ];
+        assert_eq!(min(qties).unwrap().unit(), CENTIFLOP);
+        assert_eq!(max(qties).unwrap().unit(), CENTIFLOP);
+        assert!(min::<Foo, _>([]).is_none());
+        assert!(max::<Foo, _>([]).is_none());
+    }
+
+    #[test]
+    fn test_variance() {
+        let v = variance(sample()).unwrap();
+        assert_eq!(v.unit(), SQUARE_KILOFLOP);
+        assert_almost_eq!(v.amount(), Amnt!(4));
+        let v = variance([Amnt!(17) * FLOP]).unwrap();
+        assert_eq!(v, Amnt!(0) * SQUARE_FLOP);
+        assert!(variance::<Foo, _>([]).is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_deviation() {
+        let s = std_deviation(sample()).unwrap();
+        assert_eq!(s.unit(), KILOFLOP);
+        assert_almost_eq!(s.amount(), Amnt!(2));
+        assert!(std_deviation::<Foo, _>([]).is_none());
+    }
+
+    #[test]
+    fn test_median() {
+        let m = median(sample()).unwrap();
+        assert_eq!(m.unit(), CENTIFLOP);
+        assert_almost_eq!(m.amount(), Amnt!(450000));
+        let m = median(sample()[..7].iter().copied()).unwrap();
+        assert_eq!(m, Amnt!(400000) * CENTIFLOP);
+        assert!(median::<Foo, _>([]).is_none());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let qties = sample();
+        assert_eq!(percentile(qties, Amnt!(0)), Some(Amnt!(2) * KILOFLOP));
+        assert_eq!(percentile(qties, Amnt!(100)), Some(Amnt!(9) * KILOFLOP));
+        assert_eq!(percentile(qties, Amnt!(25)), Some(Amnt!(4000) * FLOP));
+        let p = percentile(qties, Amnt!(90)).unwrap();
+        assert_eq!(p.unit(), KILOFLOP);
+        assert_almost_eq!(p.amount(), Amnt!(7.6));
+        assert!(percentile(qties, Amnt!(-1)).is_none());
+        assert!(percentile(qties, Amnt!(100.5)).is_none());
+        assert!(percentile::<Foo, _>([], Amnt!(50)).is_none());
+    }
+
+    #[test]
+    fn test_percentile_many() {
+        let qties = (0..=20000_u16).map(|i| AmountT::from(i) * FLOP);
+        let p = percentile(qties.clone(), Amnt!(37.5)).unwrap();
+        assert_eq!(p, Amnt!(7500) * FLOP);
+        let p = percentile(qties.clone(), Amnt!(12.345)).unwrap();
+        assert_almost_eq!(p.amount(), Amnt!(2469));
+        let p = percentile(qties.rev().skip(1), Amnt!(100)).unwrap();
+        assert_eq!(p, Amnt!(19999) * FLOP);
+        let qty = Amnt!(7) * KILOFLOP;
+        assert_eq!(percentile([qty], Amnt!(50)), Some(qty));
+    }
+
+    #[cfg(not(feature = "fpdec"))]
+    #[test]
+    fn test_percentile_nan() {
+        let mut qties = sample();
+        qties[3] = AmountT::NAN * FLOP;
+        assert!(percentile(qties, Amnt!(25)).is_none());
+        assert!(median(qties).is_none());
+    }
+}
@@ -0,0 +1,166 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+#[cfg(test)]
+mod vector_tests {
+    use quantities::{
+        assert_almost_eq,
+        prelude::*,
+        vector::{Vector2, Vector3},
+    };
+
+    #[quantity]
+    #[ref_unit(Flop, "f")]
+    #[unit(Kiloflop, "kf", 1000., "1000·f")]
+    #[unit(Centiflop, "cf", 0.01, "0.01·f")]
+    struct Foo {}
+
+    #[quantity(Foo * Foo)]
+    #[ref_unit(Square_Flop, "f²", "1·f²")]
+    #[unit(Square_Kiloflop, "kf²", 1000000., "1000000·f²")]
+    struct FooSquared {}
+
+    #[test]
+    fn test_new() {
+        let v = Vector3::new(
+            Amnt!(1) * FLOP,
+            Amnt!(2) * KILOFLOP,
+            Amnt!(3) * CENTIFLOP,
+        );
+        assert_eq!(v.x, Amnt!(1) * FLOP);
+        assert_eq!(v.y, Amnt!(2) * KILOFLOP);
+        assert_eq!(v.z, Amnt!(3) * CENTIFLOP);
+        assert_eq!(v.components(), [v.x, v.y, v.z]);
+        let v = Vector2::<Foo>::default();
+        assert_eq!(v.components(), [Amnt!(0) * FLOP; 2]);
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let v = Vector3::new(
+            Amnt!(1) * FLOP,
+            Amnt!(2) * KILOFLOP,
+            Amnt!(300) * CENTIFLOP,
+        );
+        let w =
+            Vector3::new(Amnt!(2) * FLOP, Amnt!(5) * FLOP, Amnt!(1) * FLOP);
+        let s = v + w;
+        assert_eq!(s.x, Amnt!(3) * FLOP);
+        assert_eq!(s.y, Amnt!(2.005) * KILOFLOP);
+        assert_eq!(s.z, Amnt!(400) * CENTIFLOP);
+        assert_eq!(s - w, v);
+        let d = v - v;
+        assert_eq!(d, -d);
+        let v = Vector2::new(Amnt!(1) * FLOP, Amnt!(-2) * KILOFLOP);
+        assert_eq!(-v, Vector2::new(Amnt!(-1) * FLOP, Amnt!(2) * KILOFLOP));
+    }
+
+    #[test]
+    fn test_scalar_mul_div() {
+        let v = Vector2::new(Amnt!(1.5) * FLOP, Amnt!(-2) * KILOFLOP);
+        let w = Vector2::new(Amnt!(3) * FLOP, Amnt!(-4) * KILOFLOP);
+        assert_eq!(v * Amnt!(2), w);
+        assert_eq!(Amnt!(2) * v, w);
+        assert_eq!(w / Amnt!(2), v);
+    }
+
+    #[test]
+    fn test_dot() {
+        let v =
+            Vector3::new(Amnt!(1) * FLOP, Amnt!(2) * FLOP, Amnt!(3) * FLOP);
+        let w = Vector3::new(
+            Amnt!(4) * FLOP,
+            Amnt!(-500) * CENTIFLOP,
+            Amnt!(0.006) * KILOFLOP,
+        );
+        let p: FooSquared = v.dot(w);
+        assert_eq!(p.unit(), SQUARE_FLOP);
+        assert_almost_eq!(p.amount(), Amnt!(12));
+        let v = Vector2::new(Amnt!(1) * KILOFLOP, Amnt!(2) * KILOFLOP);
+        let p = v.dot(v);
+        assert_eq!(p.unit(), SQUARE_KILOFLOP);
+        assert_eq!(p.amount(), Amnt!(5));
+    }
+
+    #[test]
+    fn test_cross() {
+        let v =
+            Vector3::new(Amnt!(1) * FLOP, Amnt!(2) * FLOP, Amnt!(3) * FLOP);
+        let w =
+            Vector3::new(Amnt!(4) * FLOP, Amnt!(5) * FLOP, Amnt!(6) * FLOP);
+        let c: Vector3<FooSquared> = v.cross(w);
+        assert_eq!(
+            c,
+            Vector3::new(
+                Amnt!(-3) * SQUARE_FLOP,
+                Amnt!(6) * SQUARE_FLOP,
+                Amnt!(-3) * SQUARE_FLOP
+            )
+        );
+        assert_eq!(w.cross(v), -c);
+    }
+
+    #[test]
+    fn test_convert() {
+        let v = Vector3::new(
+            Amnt!(1) * FLOP,
+            Amnt!(2) * KILOFLOP,
+            Amnt!(300) * CENTIFLOP,
+        );
+        let w = v.convert(FLOP);
+        assert_eq!(
+            w.components(),
+            [Amnt!(1) * FLOP, Amnt!(2000) * FLOP, Amnt!(3) * FLOP]
+        );
+        for qty in w.components() {
+            assert_eq!(qty.unit(), FLOP);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_norm() {
+        let v = Vector3::new(
+            Amnt!(300) * CENTIFLOP,
+            Amnt!(4) * FLOP,
+            Amnt!(0.012) * KILOFLOP,
+        );
+        let n = v.norm();
+        assert_eq!(n.unit(), CENTIFLOP);
+        assert_eq!(n.amount(), Amnt!(1300));
+        let v = Vector2::new(Amnt!(3) * KILOFLOP, Amnt!(-4000) * FLOP);
+        assert_eq!(v.norm(), Amnt!(5) * KILOFLOP);
+    }
+
+    #[test]
+    fn test_display() {
+        let v = Vector3::new(
+            Amnt!(1) * FLOP,
+            Amnt!(2) * KILOFLOP,
+            Amnt!(3) * CENTIFLOP,
+        );
+        assert_eq!(v.to_string(), "(1 f, 2 kf, 3 cf)");
+    }
+
+    #[cfg(feature = "energy")]
+    #[test]
+    fn test_work() {
+        use quantities::{
+            energy::{Energy, JOULE},
+            force::NEWTON,
+            length::{CENTIMETER, METER},
+        };
+
+        let displacement =
+            Vector2::new(Amnt!(2) * METER, Amnt!(50) * CENTIMETER);
+        let force = Vector2::new(Amnt!(3) * NEWTON, Amnt!(-2) * NEWTON);
+        let work: Energy = displacement.dot(force);
+        assert_eq!(work, Amnt!(5) * JOULE);
+    }
+}
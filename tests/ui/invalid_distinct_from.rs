@@ -0,0 +1,19 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use quantities::prelude::*;
+
+/// FooSquared, a completely useless quantity
+#[quantity(Foo * Foo, distinct = Bar)]
+#[ref_unit(A, "a", MEGA)]
+#[unit(B, "b", 0.4)]
+#[unit(C, "c", 0.01)]
+struct FooSquared {}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// ---------------------------------------------------------------------------
+// Copyright:   (c) 2026 ff. Michael Amrhein (michael@adrhinum.de)
+// License:     This program is part of a larger application. For license
+//              details please read the file LICENSE.TXT provided together
+//              with the application.
+// ---------------------------------------------------------------------------
+// $Source$
+// $Revision$
+
+use quantities::prelude::*;
+
+#[quantity]
+#[ref_unit(Flop, "f")]
+#[unit(Kiloflop, "kf", 1000., "1000·f")]
+struct Foo {}
+
+/// FooSquared, a completely useless quantity
+#[quantity(Foo * Foo, distinct_from = Nonsense)]
+#[ref_unit(A, "a", MEGA)]
+#[unit(B, "b", 0.4)]
+struct FooSquared {}
+
+fn main() {}